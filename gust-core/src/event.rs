@@ -0,0 +1,39 @@
+use crate::{people::navigation::CelestialBody, world::position::Position};
+
+/// Something that happened in the game for the player to hear about.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Event {
+    /// Game time in seconds it happened at.
+    pub time_in_seconds: u64,
+    pub kind: EventKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum EventKind {
+    /// The player reached the end of their travels.
+    Arrived { destination: Position },
+    /// The player found no way over land or sea to a destination in their plan, and let it go.
+    NoRoute { destination: Position },
+    /// The player's ship ran aground on a shore they didn't know was there.
+    RanAground,
+    /// The player came on foot to water they didn't know was in their way, and gave up on their travels.
+    StoppedAtWater { destination: Position },
+    /// The player sighted a celestial body for their latitude, and their longitude as well if they had a chronometer.
+    Fix { body: CelestialBody, longitude: bool },
+    /// The player couldn't sight a celestial body, as clouds hid the sky or it didn't rise.
+    NoSighting { body: CelestialBody },
+}
+
+impl std::fmt::Display for EventKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventKind::Arrived { destination } => write!(f, "Arrived at {}", destination),
+            EventKind::NoRoute { destination } => write!(f, "No way to {}", destination),
+            EventKind::RanAground => write!(f, "Ran aground"),
+            EventKind::StoppedAtWater { destination } => write!(f, "Stopped at the water on the way to {}", destination),
+            EventKind::Fix { body, longitude: true } => write!(f, "Fixed our position by {}", body),
+            EventKind::Fix { body, longitude: false } => write!(f, "Fixed our latitude by {}", body),
+            EventKind::NoSighting { body } => write!(f, "Couldn't sight {}", body),
+        }
+    }
+}
@@ -1,34 +1,336 @@
-use people::{person::Person, skills::Skillset, task::Task::Idle};
-use world::{globe::Globe, position::Position};
+use rand::{rngs::StdRng, SeedableRng};
+
+use event::{Event, EventKind};
+use people::{
+    navigation::Instrument,
+    person::Person,
+    plan::{Plan, PlannedTask},
+    skills::Skillset,
+    task::{Task, LISTENING_DURATION},
+};
+use ships::ship::{HullClass, Ship};
+use world::{
+    currents::{Current, CurrentField},
+    forecast::Forecast,
+    globe::{GenerationParams, Globe},
+    magnetism::MagneticField,
+    pathfinding::{Route, TravelMode},
+    position::{Course, Meters, Position},
+    settlement::{Settlement, Settlements},
+    tides::{TideState, Tides},
+    weather::{Conditions, Weather},
+    wind::{self, Wind, WindField},
+};
+
+/// Longest span of game time in seconds simulated at once.
+const TICK: u64 = 60 * 60;
+/// Game time in seconds between recomputations of the wind field as the seasons change.
+const WIND_UPDATE_INTERVAL: u64 = 6 * 60 * 60;
+/// Game time in seconds between updates of the temperature and precipitation of the globe.
+const CLIMATE_UPDATE_INTERVAL: u64 = 24 * 60 * 60;
+/// Game time in seconds the weather is run for before a new game starts, so that it begins with storms under way.
+const WEATHER_SPIN_UP: u64 = 10 * 24 * 60 * 60;
+/// Experience in wind listening gained from each forecast.
+const LISTENING_XP: f32 = 0.1;
+/// Experience in trading gained for each hour at the market.
+const TRADING_XP: f32 = 0.01;
 
 pub struct Game {
     pub time_in_seconds: u64,
     pub player: Person,
     pub globe: Globe,
+    pub wind: WindField,
+    pub currents: CurrentField,
+    pub tides: Tides,
+    pub magnetism: MagneticField,
+    pub settlements: Settlements,
+    pub weather: Weather,
+    /// What has happened in the game so far, oldest first.
+    pub events: Vec<Event>,
+    /// Source of all the chance in the game outside the weather, seeded from the world seed.
+    rng: StdRng,
 }
 
 impl Game {
-    pub fn new(time_in_seconds: u64) -> Self {
+    /// Starts a new game on a globe generated from the given seed.
+    pub fn new(time_in_seconds: u64, seed: u64) -> Self {
+        let mut globe = Globe::generate(seed, &GenerationParams::default());
+        let wind = WindField::compute(&globe, time_in_seconds);
+        globe.update_climate(&wind);
+        let currents = CurrentField::compute(&globe, &wind);
+        let tides = Tides::new(&globe, seed);
+        let settlements = Settlements::generate(&globe, seed);
+        let mut weather = Weather::new(seed);
+        weather.advance(&globe, &wind, WEATHER_SPIN_UP);
+
         Self {
             time_in_seconds,
             player: Person {
                 name: "Alaric Gale".to_string(),
                 position: Position::new(0.0, 0.0),
+                believed_position: Position::new(0.0, 0.0),
                 money: 200,
                 health: 100.0,
                 fatigue: 0.0,
                 skillset: Skillset::new(),
-                task: Idle,
+                task: Task::Idle,
+                plan: Plan::new(),
+                instruments: vec![Instrument::Quadrant],
+                ship: Some(Ship::new("Petrel", HullClass::Sloop)),
+                forecast: None,
             },
-            globe: Globe::new(),
+            globe,
+            wind,
+            currents,
+            tides,
+            magnetism: MagneticField::generate(seed),
+            settlements,
+            weather,
+            events: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
         }
     }
 
+    /// Lets game time pass, a tick at a time, so the player's travels follow the daylight and the weather as they change.
     pub fn advance_state(&mut self, seconds: u64) {
+        let mut remaining = seconds;
+        while remaining > 0 {
+            let tick = remaining.min(TICK);
+            self.tick(tick);
+            remaining -= tick;
+        }
+    }
+
+    /// Lets game time pass until the player has done the task at hand and everything in their plan, or for at most
+    /// `limit` seconds. Returns the seconds that passed.
+    pub fn advance_until_idle(&mut self, limit: u64) -> u64 {
+        let start = self.time_in_seconds;
+        self.start_next_task();
+        while !self.player.is_idle() && self.time_in_seconds - start < limit {
+            self.tick(TICK.min(limit - (self.time_in_seconds - start)));
+        }
+        self.time_in_seconds - start
+    }
+
+    fn tick(&mut self, seconds: u64) {
         self.time_in_seconds += seconds;
+
+        if self.time_in_seconds.abs_diff(self.wind.time_in_seconds) >= WIND_UPDATE_INTERVAL {
+            self.wind = WindField::compute(&self.globe, self.time_in_seconds);
+            self.currents = CurrentField::compute(&self.globe, &self.wind);
+        }
+        if self.time_in_seconds.abs_diff(self.globe.climate_time_in_seconds) >= CLIMATE_UPDATE_INTERVAL {
+            self.globe.update_climate(&self.wind);
+        }
+        self.weather.advance(&self.globe, &self.wind, seconds);
+
+        self.progress_travel(seconds);
+        self.update_player_task(seconds);
+    }
+
+    /// Sets the player to listen to the wind for a while, after which they make a forecast of the weather.
+    pub fn start_listening(&mut self) {
+        self.player.task = Task::Listening {
+            until: self.time_in_seconds + LISTENING_DURATION,
+        };
+    }
+
+    /// The quickest way on foot for the player from where they believe they are to a destination, or `None` if there is
+    /// no way over land.
+    pub fn plan_land_route(&self, destination: &Position) -> Option<Route> {
+        let skill = self.player.skillset.traveling.get();
+        self.globe.find_land_route(&self.player.believed_position, destination, skill)
+    }
+
+    /// The fastest way under sail for the player from where they believe they are to a destination, in their ship as they captain
+    /// it, with the winds and currents from now on. `None` if they have no ship fit to sail, either end lies inland
+    /// or the sea doesn't join them.
+    pub fn plan_sea_route(&self, destination: &Position) -> Option<Route> {
+        let ship = self.player.ship.as_ref().filter(|ship| ship.can_sail())?;
+        let polar = ship.sailing_polar(self.player.skillset.sailing.get());
+        self.globe
+            .find_sea_route(&self.player.believed_position, destination, self.time_in_seconds, &self.currents, &polar)
+    }
+
+    /// The quickest way for the player from where they believe they are to a destination: on foot when they stand on land,
+    /// or else under sail. Where there is no way over land, they take to the sea from the coast.
+    pub fn plan_route(&self, destination: &Position) -> Option<Route> {
+        let on_land = self.globe.cells[self.globe.cell_at(&self.player.believed_position)].is_land();
+        on_land.then(|| self.plan_land_route(destination)).flatten().or_else(|| self.plan_sea_route(destination))
+    }
+
+    /// Sets the player off towards a destination by the route found from where they are. Ships setting out from a
+    /// harbor wait for the tide first. Returns whether there was a way there, leaving the task at hand as it was if not.
+    pub fn travel_to(&mut self, destination: Position, course: Course) -> bool {
+        let Some(route) = self.plan_route(&destination) else {
+            return false;
+        };
+        if route.mode == TravelMode::Sailing {
+            match self.ship_tide_window(&self.player.position) {
+                None => return false,
+                Some(until) if until > self.time_in_seconds => {
+                    self.wait_for_tide(until, destination, course);
+                    return true;
+                }
+                Some(_) => (),
+            }
+        }
+        self.player.task = Task::Traveling {
+            destination,
+            course,
+            route: Some(route),
+        };
+        true
+    }
+
+    /// Sets the player about the next task in their plan if they have nothing at hand. Travel to a destination
+    /// there is no way to is let go of, with an event for the player to hear about it, and the plan goes on.
+    pub fn start_next_task(&mut self) {
+        while matches!(self.player.task, Task::Idle) {
+            let Some(next) = self.player.plan.take_next() else {
+                return;
+            };
+            match next {
+                PlannedTask::Travel { destination, course } => {
+                    if !self.travel_to(destination, course) {
+                        self.events.push(Event {
+                            time_in_seconds: self.time_in_seconds,
+                            kind: EventKind::NoRoute { destination },
+                        });
+                    }
+                }
+                PlannedTask::Rest { duration } => {
+                    self.player.task = Task::Resting {
+                        until: self.time_in_seconds + duration,
+                    }
+                }
+                PlannedTask::Trade { duration } => {
+                    self.player.task = Task::Trading {
+                        until: self.time_in_seconds + duration,
+                    }
+                }
+                PlannedTask::Listen => self.start_listening(),
+                PlannedTask::Sight => self.start_sighting(),
+            }
+        }
+    }
+
+    /// Carries on with the task of the player over a span of seconds, completes it once it is done, and sets them
+    /// about the next one in their plan.
+    fn update_player_task(&mut self, seconds: u64) {
+        let hours = seconds as f32 / (60.0 * 60.0);
+        match self.player.task {
+            Task::Listening { until } if self.time_in_seconds >= until => {
+                let skill = &mut self.player.skillset.wind_listening;
+                self.player.forecast = Some(Forecast::listen(
+                    &self.globe,
+                    &self.wind,
+                    &self.weather,
+                    &self.player.position,
+                    self.time_in_seconds,
+                    skill.get(),
+                    &mut self.rng,
+                ));
+                skill.gain_xp(LISTENING_XP);
+                self.player.task = Task::Idle;
+            }
+            Task::Resting { until } => {
+                self.player.rest(hours);
+                if self.time_in_seconds >= until {
+                    self.player.task = Task::Idle;
+                }
+            }
+            Task::Trading { until } => {
+                self.player.skillset.trading.gain_xp(TRADING_XP * hours);
+                if self.time_in_seconds >= until {
+                    self.player.task = Task::Idle;
+                }
+            }
+            Task::WaitingForTide { until } => {
+                self.player.rest(hours);
+                if self.time_in_seconds >= until {
+                    self.player.task = Task::Idle;
+                }
+            }
+            Task::Sighting { .. } => self.progress_sighting(seconds),
+            _ => (),
+        }
+
+        self.start_next_task();
+    }
+
+    /// The current prevailing wind at a position.
+    pub fn wind_at(&self, position: &Position) -> Wind {
+        self.wind.at(&self.globe, position)
+    }
+
+    /// The prevailing wind at a position at any moment, past or future.
+    pub fn wind_at_time(&self, position: &Position, time_in_seconds: u64) -> Wind {
+        wind::prevailing_wind_at(&self.globe, position, time_in_seconds)
+    }
+
+    /// The current flowing at the surface of the sea at a position. Still water on land, easing off towards the coast.
+    pub fn current_at(&self, position: &Position) -> Current {
+        self.currents.at(&self.globe, position)
+    }
+
+    /// The tide at the coast nearest a position, or `None` away from the coasts.
+    pub fn tide_at(&self, position: &Position) -> Option<TideState> {
+        self.tides.state(&self.globe, self.globe.cell_at(position), self.time_in_seconds)
+    }
+
+    /// The first moment from now a ship drawing the given depth of water can cross into or out of the harbor at a
+    /// position, or `None` if the tide won't rise high enough for it over the next couple of days.
+    pub fn next_tide_window(&self, position: &Position, draft: Meters) -> Option<u64> {
+        self.tides.next_passable(&self.globe, self.globe.cell_at(position), self.time_in_seconds, draft)
+    }
+
+    /// The first moment from now the player's ship can cross into or out of the harbor at a position, or `None` if
+    /// the tide won't rise high enough for it. Now for players without a ship.
+    fn ship_tide_window(&self, position: &Position) -> Option<u64> {
+        match &self.player.ship {
+            Some(ship) => self.next_tide_window(position, ship.draft),
+            None => Some(self.time_in_seconds),
+        }
+    }
+
+    /// Sets the player to wait for the tide until the given game time in seconds, then head for a destination.
+    fn wait_for_tide(&mut self, until: u64, destination: Position, course: Course) {
+        self.player.plan.insert(0, PlannedTask::Travel { destination, course });
+        self.player.task = Task::WaitingForTide { until };
+    }
+
+    /// Magnetic declination in radians at a position, the angle compass needles point east of true north.
+    pub fn declination_at(&self, position: &Position) -> f64 {
+        self.magnetism.declination(position)
+    }
+
+    /// The settlement standing where a position lies, if any.
+    pub fn settlement_at(&self, position: &Position) -> Option<&Settlement> {
+        self.settlements.at_cell(self.globe.cell_at(position))
+    }
+
+    /// The settlement nearest a position, or `None` if no one has settled anywhere.
+    pub fn nearest_settlement(&self, position: &Position) -> Option<&Settlement> {
+        self.settlements.nearest(position)
+    }
+
+    /// Whether the sun is up at a position, for travel that can only go on by day.
+    pub fn is_daylight(&self, position: &Position) -> bool {
+        self.globe.astronomy.is_daylight(self.time_in_seconds, position)
+    }
+
+    /// The current weather at a position, including the storms, cyclones and calms passing over it.
+    pub fn conditions_at(&self, position: &Position) -> Conditions {
+        self.weather.conditions_at(&self.globe, &self.wind, position)
     }
 }
 
+pub mod event;
 pub mod people;
+pub mod ships;
+mod sighting;
+pub mod trade;
+mod travel;
 pub mod util;
 pub mod world;
@@ -1,3 +1,5 @@
+pub mod navigation;
 pub mod person;
+pub mod plan;
 pub mod skills;
 pub mod task;
@@ -0,0 +1,140 @@
+use crate::world::position::{Meters, Position};
+
+use super::{person::Person, skills::MAX_SKILL};
+
+/// Distance in meters at which a coastline or a settlement can be made out in clear weather.
+pub const SIGHTING_RANGE: Meters = 30_000.0;
+/// Largest error in radians, either way, of the heading kept by the least and the most skilled travelers.
+const NOVICE_STEERING_ERROR: f64 = 0.2;
+const MASTER_STEERING_ERROR: f64 = 0.02;
+/// Chance a master recognizes where they are on land away from any landmark, each time they look around.
+const MASTER_LANDMARK_CHANCE: f64 = 0.1;
+/// Share of the magnetic declination allowed for by the least skilled travelers, from a rough idea of it.
+const NOVICE_DECLINATION_CORRECTION: f64 = 0.5;
+/// Largest error in radians, either way, of an altitude reckoned by eye against outstretched hands.
+const NAKED_EYE_ERROR: f64 = 0.03;
+/// How many times the error of their instrument the least skilled observers are off by, on top of it.
+const UNSKILLED_SIGHTING: f64 = 1.0;
+/// How many times the error of a sighting grows, on top of it, in the roughest weather as the deck pitches.
+const ROUGH_SEA_SIGHTING: f64 = 2.0;
+/// Largest error in radians, either way, of a longitude from the time kept by a chronometer, as it drifts.
+const CHRONOMETER_ERROR: f64 = 0.003;
+
+/// Something a traveler sees that tells them where they are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Observation {
+    /// A landmark they know, which shows them just where they are.
+    Landmark,
+    /// A coastline in sight, which shows them they are within sight of it.
+    Coastline,
+    /// A sighting of the heavens, giving their latitude in radians, and their longitude as well when they know the time.
+    Celestial { latitude: f64, longitude: Option<f64> },
+}
+
+/// Instruments for finding one's way by the heavens.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Instrument {
+    /// A quarter circle with a plumb line, sighted along its edge at the sun or a star.
+    Quadrant,
+    /// A staff read with the back to the sun, by the shadow its vane casts.
+    Backstaff,
+    /// An eighth of a circle with mirrors, which brings the sun down to the horizon.
+    Octant,
+    /// A clock that keeps the time of the prime meridian at sea, against which the local time gives the longitude.
+    Chronometer,
+}
+
+impl Instrument {
+    /// Largest error in radians, either way, of the altitudes measured with this instrument by a master,
+    /// or `None` if it doesn't measure them.
+    pub fn altitude_error(&self) -> Option<f64> {
+        match self {
+            Instrument::Quadrant => Some(0.005),
+            Instrument::Backstaff => Some(0.003),
+            Instrument::Octant => Some(0.001),
+            Instrument::Chronometer => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Instrument {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Instrument::Quadrant => "Quadrant",
+            Instrument::Backstaff => "Backstaff",
+            Instrument::Octant => "Octant",
+            Instrument::Chronometer => "Chronometer",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// What is sighted to find the latitude.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CelestialBody {
+    /// The sun at its highest, at local noon, which stands as far from overhead as the latitude is from its declination.
+    NoonSun,
+    /// The pole star, which stands as high over the northern horizon as the latitude.
+    Polaris,
+}
+
+impl std::fmt::Display for CelestialBody {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CelestialBody::NoonSun => write!(f, "the noon sun"),
+            CelestialBody::Polaris => write!(f, "Polaris"),
+        }
+    }
+}
+
+impl Person {
+    /// How far in meters where this person believes they are lies from where they are, on a globe of the given radius.
+    pub fn position_error(&self, radius: Meters) -> Meters {
+        self.believed_position.distance_to(&self.position, radius)
+    }
+
+    /// Corrects where this person believes they are with something they see, on a globe of the given radius.
+    pub fn observe(&mut self, observation: Observation, radius: Meters) {
+        match observation {
+            Observation::Landmark => self.believed_position = self.position,
+            Observation::Coastline => {
+                // The coast only tells how far off it they are, so they are set right to within sight of it.
+                let error = self.position_error(radius);
+                if error > SIGHTING_RANGE {
+                    self.believed_position = self.believed_position.interpolate(&self.position, 1.0 - SIGHTING_RANGE / error);
+                }
+            }
+            Observation::Celestial { latitude, longitude } => {
+                self.believed_position = Position::new(latitude, longitude.unwrap_or(self.believed_position.longitude()));
+            }
+        }
+    }
+}
+
+/// Largest error in radians, either way, of the heading kept by a traveler of the given skill.
+pub fn steering_error(skill: f32) -> f64 {
+    let mastery = (skill / MAX_SKILL).min(1.0) as f64;
+    NOVICE_STEERING_ERROR + (MASTER_STEERING_ERROR - NOVICE_STEERING_ERROR) * mastery
+}
+
+/// Largest errors in radians, either way, of the latitude, and of the longitude if there is a chronometer, found from
+/// a sighting with some instruments by an observer of the given navigation skill, in weather of the given danger.
+pub fn sighting_error(instruments: &[Instrument], skill: f32, danger: f32) -> (f64, Option<f64>) {
+    let instrument_error = instruments.iter().filter_map(Instrument::altitude_error).fold(NAKED_EYE_ERROR, f64::min);
+    let unskilled = 1.0 - (skill / MAX_SKILL).min(1.0) as f64;
+    let latitude = instrument_error * (1.0 + UNSKILLED_SIGHTING * unskilled) * (1.0 + ROUGH_SEA_SIGHTING * danger as f64);
+    let longitude = instruments.contains(&Instrument::Chronometer).then_some(latitude + CHRONOMETER_ERROR);
+    (latitude, longitude)
+}
+
+/// Share of the magnetic declination a traveler of the given navigation skill allows for when steering by compass.
+/// The rest of it turns them off their heading.
+pub fn declination_correction(skill: f32) -> f64 {
+    let mastery = (skill / MAX_SKILL).min(1.0) as f64;
+    NOVICE_DECLINATION_CORRECTION + (1.0 - NOVICE_DECLINATION_CORRECTION) * mastery
+}
+
+/// Chance a traveler of the given navigation skill recognizes where they are on land away from any landmark.
+pub fn landmark_chance(skill: f32) -> f64 {
+    MASTER_LANDMARK_CHANCE * (skill / MAX_SKILL).min(1.0) as f64
+}
@@ -1,13 +1,44 @@
-use crate::world::position::Position;
+use crate::{
+    ships::ship::Ship,
+    world::{forecast::Forecast, position::Position},
+};
 
-use super::{skills::Skillset, task::Task};
+use super::{navigation::Instrument, plan::Plan, skills::Skillset, task::Task};
+
+/// Fatigue lost for each hour of rest.
+const RECOVERY: f32 = 10.0;
 
 pub struct Person {
     pub name: String,
+    /// Where this person is.
     pub position: Position,
+    /// Where this person believes they are, reckoned from the way they have come since they last saw where they were.
+    pub believed_position: Position,
     pub money: i64,
     pub health: f32,
+    /// How tired this person is, from zero when fully rested to 100 when too exhausted to go on.
     pub fatigue: f32,
     pub skillset: Skillset,
+    /// The task at hand.
     pub task: Task,
+    /// The tasks to set about once the one at hand is done.
+    pub plan: Plan,
+    /// The instruments this person finds their way with.
+    pub instruments: Vec<Instrument>,
+    /// The ship this person owns and captains.
+    pub ship: Option<Ship>,
+    /// The last forecast this person made by listening to the wind.
+    pub forecast: Option<Forecast>,
+}
+
+impl Person {
+    /// Whether this person has nothing at hand and nothing planned.
+    pub fn is_idle(&self) -> bool {
+        matches!(self.task, Task::Idle) && self.plan.is_empty()
+    }
+
+    /// Recovers from fatigue over hours of rest.
+    pub fn rest(&mut self, hours: f32) {
+        self.fatigue = (self.fatigue - RECOVERY * hours).max(0.0);
+    }
 }
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+
+use crate::world::position::{Course, Position};
+
+/// A task waiting its turn in a person's plan, to be set about once the tasks before it are done.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum PlannedTask {
+    /// Travel to a destination, over land or sea, by the way found when setting off.
+    Travel { destination: Position, course: Course },
+    /// Rest for a span of seconds.
+    Rest { duration: u64 },
+    /// Trade at the market for a span of seconds.
+    Trade { duration: u64 },
+    /// Listen to the wind and make a forecast.
+    Listen,
+    /// Sight the sun or a star for a fix.
+    Sight,
+}
+
+impl std::fmt::Display for PlannedTask {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlannedTask::Travel { destination, .. } => write!(f, "Travel to {}", destination),
+            PlannedTask::Rest { duration } => write!(f, "Rest for {}h", duration.div_ceil(3600)),
+            PlannedTask::Trade { duration } => write!(f, "Trade for {}h", duration.div_ceil(3600)),
+            PlannedTask::Listen => write!(f, "Listen to the wind"),
+            PlannedTask::Sight => write!(f, "Take a sighting"),
+        }
+    }
+}
+
+/// The tasks a person means to set about after the one at hand, in order. Travel through several waypoints
+/// is planned as a travel task to each in turn.
+#[derive(Clone, Debug, Default)]
+pub struct Plan {
+    tasks: VecDeque<PlannedTask>,
+}
+
+impl Plan {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a task to the end of the plan.
+    pub fn push(&mut self, task: PlannedTask) {
+        self.tasks.push_back(task);
+    }
+
+    /// Adds a task at a place in the plan, before the task that was there. Places past the end add it to the end.
+    pub fn insert(&mut self, index: usize, task: PlannedTask) {
+        self.tasks.insert(index.min(self.tasks.len()), task);
+    }
+
+    /// Moves a task from one place in the plan to another, shifting the tasks between them.
+    /// Returns whether there was a task to move.
+    pub fn reorder(&mut self, from: usize, to: usize) -> bool {
+        let Some(task) = self.tasks.remove(from) else {
+            return false;
+        };
+        self.insert(to, task);
+        true
+    }
+
+    /// Takes a task out of the plan, returning it if there was one at that place.
+    pub fn cancel(&mut self, index: usize) -> Option<PlannedTask> {
+        self.tasks.remove(index)
+    }
+
+    pub fn clear(&mut self) {
+        self.tasks.clear();
+    }
+
+    /// Takes the first task out of the plan, to set about it.
+    pub fn take_next(&mut self) -> Option<PlannedTask> {
+        self.tasks.pop_front()
+    }
+
+    pub fn get(&self, index: usize) -> Option<&PlannedTask> {
+        self.tasks.get(index)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PlannedTask> {
+        self.tasks.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+}
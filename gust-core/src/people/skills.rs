@@ -1,7 +1,8 @@
 const MIN_SKILL: f32 = 0.25;
-const MAX_SKILL: f32 = 10.0;
+/// Skill of a master, which no amount of experience takes anyone past.
+pub const MAX_SKILL: f32 = 10.0;
 
-struct Skill {
+pub struct Skill {
     talent: f32,
     accumulated_xp: f32,
     skill: f32,
@@ -45,18 +46,26 @@ impl Skill {
 }
 
 pub struct Skillset {
-    traveling: Skill,
-    sailing: Skill,
-    wind_listening: Skill,
-    trading: Skill,
+    pub traveling: Skill,
+    pub sailing: Skill,
+    pub navigation: Skill,
+    pub wind_listening: Skill,
+    pub trading: Skill,
 }
 impl Skillset {
     pub fn new() -> Skillset {
         Self {
             traveling: Skill::new(1.0, 0.0),
             sailing: Skill::new(1.0, 0.0),
+            navigation: Skill::new(1.0, 0.0),
             wind_listening: Skill::new(1.0, 0.0),
             trading: Skill::new(1.0, 0.0),
         }
     }
 }
+
+impl Default for Skillset {
+    fn default() -> Self {
+        Self::new()
+    }
+}
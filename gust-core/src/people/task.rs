@@ -1,15 +1,65 @@
-use crate::world::position::Position;
+use crate::world::{
+    pathfinding::{Route, TravelMode},
+    position::{Course, Position},
+};
+
+use super::navigation::CelestialBody;
+
+/// Seconds spent listening to the wind to make a forecast.
+pub const LISTENING_DURATION: u64 = 60 * 60;
 
 pub enum Task {
     Idle,
-    Traveling(Position),
+    /// Heading for a destination, through the waypoints of a route if there is one,
+    /// or else straight there along the course.
+    Traveling {
+        destination: Position,
+        course: Course,
+        route: Option<Route>,
+    },
+    /// Reading the wind until the given game time in seconds, then making a forecast.
+    Listening {
+        until: u64,
+    },
+    /// Resting until the given game time in seconds.
+    Resting {
+        until: u64,
+    },
+    /// Trading at the market until the given game time in seconds.
+    Trading {
+        until: u64,
+    },
+    /// Waiting in harbor or off it until the given game time in seconds, when the tide rises high enough for the ship
+    /// to cross.
+    WaitingForTide {
+        until: u64,
+    },
+    /// Waiting to sight a celestial body until the given game time in seconds, when it can be sighted.
+    Sighting {
+        body: CelestialBody,
+        until: u64,
+    },
 }
 
 impl std::fmt::Display for Task {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Task::Idle => write!(f, "Idle"),
-            Task::Traveling(pos) => write!(f, "Traveling to {}", pos),
+            Task::Traveling {
+                destination, route: Some(route), ..
+            } => {
+                let verb = match route.mode {
+                    TravelMode::Walking => "Traveling",
+                    TravelMode::Sailing => "Sailing",
+                };
+                write!(f, "{} to {} in ~{}h", verb, destination, route.duration.div_ceil(3600))
+            }
+            Task::Traveling { destination, course, .. } => write!(f, "Traveling to {} by {}", destination, course),
+            Task::Listening { .. } => write!(f, "Listening to the wind"),
+            Task::Resting { .. } => write!(f, "Resting"),
+            Task::Trading { .. } => write!(f, "Trading at the market"),
+            Task::WaitingForTide { .. } => write!(f, "Waiting for the tide"),
+            Task::Sighting { body, .. } => write!(f, "Waiting to sight {}", body),
         }
     }
 }
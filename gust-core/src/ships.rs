@@ -0,0 +1,2 @@
+pub mod polar;
+pub mod ship;
@@ -0,0 +1,97 @@
+use std::f32::consts::{PI, TAU};
+
+use glam::Vec2;
+
+use crate::world::wind::Wind;
+
+/// Number of headings, evenly spaced around the compass, tried when working out the speed made good on a bearing.
+const MADE_GOOD_HEADINGS: usize = 72;
+
+/// How fast a ship sails through the water at each angle to the wind.
+///
+/// The speed is a fraction of the speed of the true wind, read off a table from dead upwind to dead downwind
+/// and blended between its entries. No ship sails faster than its hull lets it, however hard the wind blows.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SpeedPolar {
+    /// True wind angles in radians off the bow, rising from 0 to π, paired with the fraction of the speed
+    /// of the wind the ship sails at on that angle.
+    pub points: Vec<(f32, f32)>,
+    /// Fastest the hull can be driven through the water, in m/s.
+    pub max_speed: f32,
+}
+
+impl SpeedPolar {
+    /// Constructs a polar from pairs of true wind angles in degrees off the bow and the fraction
+    /// of the speed of the wind sailed at on each angle.
+    pub fn from_degrees(points: &[(f32, f32)], max_speed: f32) -> Self {
+        Self {
+            points: points.iter().map(|&(angle, ratio)| (angle.to_radians(), ratio)).collect(),
+            max_speed,
+        }
+    }
+
+    /// The same polar for a ship sailed a fraction as fast on every angle, such as one short of hands or in poor repair.
+    pub fn scaled(&self, factor: f32) -> Self {
+        Self {
+            points: self.points.iter().map(|&(angle, ratio)| (angle, ratio * factor)).collect(),
+            max_speed: self.max_speed * factor,
+        }
+    }
+
+    /// Speed through the water in m/s, sailing at a true wind angle in radians off the bow, on either tack,
+    /// in a wind of the given speed in m/s.
+    pub fn speed(&self, true_wind_angle: f32, wind_speed: f32) -> f32 {
+        let angle = true_wind_angle.rem_euclid(TAU);
+        let angle = if angle > PI { TAU - angle } else { angle };
+
+        let ratio = match self.points.iter().position(|&(point_angle, _)| point_angle >= angle) {
+            Some(0) => self.points[0].1,
+            Some(index) => {
+                let (low_angle, low_ratio) = self.points[index - 1];
+                let (high_angle, high_ratio) = self.points[index];
+                low_ratio + (high_ratio - low_ratio) * (angle - low_angle) / (high_angle - low_angle)
+            }
+            None => self.points.last().map_or(0.0, |&(_, ratio)| ratio),
+        };
+
+        (ratio * wind_speed).min(self.max_speed)
+    }
+
+    /// Velocity through the water in the east and north m/s, sailing on a heading in radians clockwise
+    /// from north with a wind blowing.
+    pub fn velocity(&self, heading: f32, wind: Wind) -> Vec2 {
+        let speed = self.speed(heading - wind.direction(), wind.speed());
+        Vec2::new(heading.sin(), heading.cos()) * speed
+    }
+
+    /// Speed in m/s the ship closes on a bearing in radians clockwise from north, sailing whichever heading gets
+    /// it there fastest in a wind. Against the wind, that is tacking back and forth across the bearing.
+    pub fn made_good(&self, bearing: f32, wind: Wind) -> f32 {
+        (0..MADE_GOOD_HEADINGS)
+            .map(|heading| {
+                let offset = heading as f32 * TAU / MADE_GOOD_HEADINGS as f32;
+                self.speed(bearing + offset - wind.direction(), wind.speed()) * offset.cos()
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+impl Default for SpeedPolar {
+    /// A small fore-and-aft rigged trader, which can sail no closer than 45° to the wind,
+    /// and is fastest with the wind on the beam.
+    fn default() -> Self {
+        Self::from_degrees(
+            &[
+                (0.0, 0.0),
+                (40.0, 0.0),
+                (50.0, 0.35),
+                (60.0, 0.45),
+                (90.0, 0.55),
+                (120.0, 0.52),
+                (150.0, 0.43),
+                (180.0, 0.36),
+            ],
+            5.0,
+        )
+    }
+}
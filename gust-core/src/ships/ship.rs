@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+
+use crate::{people::skills::MAX_SKILL, trade::good::Good, world::position::Meters};
+
+use super::polar::SpeedPolar;
+
+/// Share of its best speed a ship makes with the least skilled captain, rising to all of it with the most skilled.
+const UNSKILLED_HANDLING: f32 = 0.6;
+/// Share of its best speed a ship makes with the fewest hands it can be sailed with, rising to all of it with a full crew.
+const SHORT_HANDED: f32 = 0.7;
+/// Share of its best speed a ship makes on the verge of sinking, rising to all of it when sound.
+const WORN_OUT: f32 = 0.5;
+/// Condition lost for each hour at sea, and for each hour in the most dangerous weather on top of it.
+const WEAR: f32 = 0.0001;
+const STORM_WEAR: f32 = 0.001;
+
+/// The kinds of ships, each with its rig, size and hold.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HullClass {
+    /// A small single-masted fore-and-aft rigged boat, handy and shallow, for coastal trade.
+    Sloop,
+    /// A nimble lateen rigged ship that sails close to the wind, for voyages of discovery.
+    Caravel,
+    /// A broad square rigged merchantman with a deep hold, slow and poor to windward.
+    Cog,
+    /// A large ocean going ship, square rigged with a lateen mizzen, with the biggest hold afloat.
+    Carrack,
+}
+
+impl HullClass {
+    /// How fast the hull sails at each angle to the wind, in the hands of a master with a full crew.
+    pub fn polar(&self) -> SpeedPolar {
+        match self {
+            HullClass::Sloop => SpeedPolar::default(),
+            HullClass::Caravel => SpeedPolar::from_degrees(
+                &[
+                    (0.0, 0.0),
+                    (35.0, 0.0),
+                    (45.0, 0.32),
+                    (60.0, 0.46),
+                    (90.0, 0.56),
+                    (120.0, 0.55),
+                    (150.0, 0.47),
+                    (180.0, 0.4),
+                ],
+                6.0,
+            ),
+            HullClass::Cog => SpeedPolar::from_degrees(&[(0.0, 0.0), (60.0, 0.0), (70.0, 0.25), (90.0, 0.4), (120.0, 0.5), (150.0, 0.5), (180.0, 0.45)], 4.5),
+            HullClass::Carrack => SpeedPolar::from_degrees(&[(0.0, 0.0), (55.0, 0.0), (65.0, 0.3), (90.0, 0.45), (120.0, 0.52), (150.0, 0.52), (180.0, 0.46)], 5.5),
+        }
+    }
+
+    /// Depth of water in meters the hull needs to float.
+    pub fn draft(&self) -> Meters {
+        match self {
+            HullClass::Sloop => 1.5,
+            HullClass::Caravel => 2.5,
+            HullClass::Cog => 3.0,
+            HullClass::Carrack => 4.5,
+        }
+    }
+
+    /// The fewest hands the ship can be sailed with, and the hands it takes to sail her at her best.
+    pub fn crew(&self) -> (u32, u32) {
+        match self {
+            HullClass::Sloop => (2, 4),
+            HullClass::Caravel => (12, 20),
+            HullClass::Cog => (8, 14),
+            HullClass::Carrack => (40, 70),
+        }
+    }
+
+    /// Tonnes of cargo the hold takes.
+    pub fn cargo_capacity(&self) -> f32 {
+        match self {
+            HullClass::Sloop => 20.0,
+            HullClass::Caravel => 60.0,
+            HullClass::Cog => 120.0,
+            HullClass::Carrack => 400.0,
+        }
+    }
+}
+
+impl std::fmt::Display for HullClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            HullClass::Sloop => "Sloop",
+            HullClass::Caravel => "Caravel",
+            HullClass::Cog => "Cog",
+            HullClass::Carrack => "Carrack",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A ship, with its crew and the cargo in its hold.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ship {
+    pub name: String,
+    pub hull: HullClass,
+    /// How fast the ship sails at each angle to the wind, in the hands of a master with a full crew while sound.
+    pub polar: SpeedPolar,
+    /// Depth of water in meters the ship needs to float.
+    pub draft: Meters,
+    /// The fewest hands the ship can be sailed with.
+    pub min_crew: u32,
+    /// The hands it takes to sail the ship at her best.
+    pub full_crew: u32,
+    /// The hands aboard.
+    pub crew: u32,
+    /// Tonnes of cargo the hold takes.
+    pub cargo_capacity: f32,
+    /// Tonnes of each good in the hold.
+    pub cargo: HashMap<Good, f32>,
+    /// State of the hull and rigging, from zero when about to sink to one when sound.
+    pub condition: f32,
+}
+
+impl Ship {
+    /// A sound ship of a hull class, with a full crew and an empty hold.
+    pub fn new(name: &str, hull: HullClass) -> Self {
+        let (min_crew, full_crew) = hull.crew();
+        Self {
+            name: name.to_string(),
+            hull,
+            polar: hull.polar(),
+            draft: hull.draft(),
+            min_crew,
+            full_crew,
+            crew: full_crew,
+            cargo_capacity: hull.cargo_capacity(),
+            cargo: HashMap::new(),
+            condition: 1.0,
+        }
+    }
+
+    /// Whether there are enough hands aboard to sail, and the ship is still afloat.
+    pub fn can_sail(&self) -> bool {
+        self.crew >= self.min_crew && self.condition > 0.0
+    }
+
+    /// Share of the speed of its polar the ship makes with a captain of the given sailing skill, the hands aboard
+    /// and the state it is in. Zero if it can't sail.
+    pub fn performance(&self, sailing_skill: f32) -> f32 {
+        if !self.can_sail() {
+            return 0.0;
+        }
+        let handling = UNSKILLED_HANDLING + (1.0 - UNSKILLED_HANDLING) * (sailing_skill / MAX_SKILL).min(1.0);
+        let hands = match self.full_crew - self.min_crew {
+            0 => 1.0,
+            spare => ((self.crew - self.min_crew) as f32 / spare as f32).min(1.0),
+        };
+        let crew = SHORT_HANDED + (1.0 - SHORT_HANDED) * hands;
+        let condition = WORN_OUT + (1.0 - WORN_OUT) * self.condition;
+        handling * crew * condition
+    }
+
+    /// How fast the ship sails at each angle to the wind under a captain of the given sailing skill.
+    pub fn sailing_polar(&self, sailing_skill: f32) -> SpeedPolar {
+        self.polar.scaled(self.performance(sailing_skill))
+    }
+
+    /// Wears the hull and rigging down over hours at sea, in weather of the given danger, from zero in fair
+    /// weather to one in a hurricane.
+    pub fn wear(&mut self, hours: f32, danger: f32) {
+        self.damage((WEAR + STORM_WEAR * danger) * hours);
+    }
+
+    /// Damages the hull and rigging, taking some of their condition away.
+    pub fn damage(&mut self, amount: f32) {
+        self.condition = (self.condition - amount).max(0.0);
+    }
+
+    /// Tonnes of cargo in the hold.
+    pub fn cargo_weight(&self) -> f32 {
+        self.cargo.values().sum()
+    }
+
+    /// Loads as much of some tonnes of a good as the hold has room for, returning the tonnes loaded.
+    pub fn load(&mut self, good: Good, tonnes: f32) -> f32 {
+        let loaded = tonnes.min(self.cargo_capacity - self.cargo_weight()).max(0.0);
+        if loaded > 0.0 {
+            *self.cargo.entry(good).or_insert(0.0) += loaded;
+        }
+        loaded
+    }
+
+    /// Unloads as much of some tonnes of a good as there is in the hold, returning the tonnes unloaded.
+    pub fn unload(&mut self, good: Good, tonnes: f32) -> f32 {
+        let Some(stored) = self.cargo.get_mut(&good) else {
+            return 0.0;
+        };
+        let unloaded = tonnes.min(*stored).max(0.0);
+        *stored -= unloaded;
+        if *stored <= 0.0 {
+            self.cargo.remove(&good);
+        }
+        unloaded
+    }
+}
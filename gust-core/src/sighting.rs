@@ -0,0 +1,89 @@
+use std::f64::consts::FRAC_PI_2;
+
+use rand::Rng;
+
+use crate::{
+    event::{Event, EventKind},
+    people::{
+        navigation::{self, CelestialBody, Observation},
+        plan::PlannedTask,
+        task::Task,
+    },
+    Game,
+};
+
+/// Seconds spent sighting a star and working out the latitude from it.
+const SIGHTING_DURATION: u64 = 30 * 60;
+/// Lowest latitude in radians from which Polaris stands clear enough of the horizon to be sighted.
+const POLARIS_LATITUDE: f64 = 0.09;
+/// Rain in mm per hour that comes with clouds thick enough to hide the sky.
+const OVERCAST_RAIN: f32 = 1.0;
+/// Experience in navigation gained from each fix.
+const SIGHTING_XP: f32 = 0.05;
+
+impl Game {
+    /// Sets the player to sight Polaris if it shines, or else to wait for the sun to reach its highest at local noon,
+    /// for a fix of their latitude. Travels under way are taken up again after the sighting, with the way planned
+    /// afresh from the fix.
+    pub fn start_sighting(&mut self) {
+        if let Task::Traveling { destination, course, .. } = self.player.task {
+            self.player.plan.insert(0, PlannedTask::Travel { destination, course });
+        }
+
+        let position = self.player.position;
+        let astronomy = &self.globe.astronomy;
+        let (body, until) = if !self.is_daylight(&position) && position.latitude() >= POLARIS_LATITUDE {
+            (CelestialBody::Polaris, self.time_in_seconds + SIGHTING_DURATION)
+        } else {
+            let noon = astronomy.day_length / 2;
+            let time = astronomy.local_solar_time(self.time_in_seconds, &position);
+            let wait = (noon + astronomy.day_length - time) % astronomy.day_length;
+            (CelestialBody::NoonSun, self.time_in_seconds + wait)
+        };
+        self.player.task = Task::Sighting { body, until };
+    }
+
+    /// Waits for the sighting of the player, at sea drifting with the current, and takes it once the time comes.
+    /// The sighting fixes their latitude, and their longitude as well if they have a chronometer, as precisely as
+    /// their instruments, their navigation skill and the weather let them, unless clouds hide the sky.
+    pub(crate) fn progress_sighting(&mut self, seconds: u64) {
+        let Task::Sighting { body, until } = self.player.task else {
+            return;
+        };
+        let position = self.player.position;
+        if !self.globe.cells[self.globe.cell_at(&position)].is_land() {
+            let drifted = self.currents.drift(&self.globe, &position, seconds);
+            self.carry_ship(drifted, &position);
+        }
+        if self.time_in_seconds < until {
+            return;
+        }
+
+        self.player.task = Task::Idle;
+        let position = self.player.position;
+        let conditions = self.conditions_at(&position);
+        let risen = match body {
+            CelestialBody::NoonSun => self.globe.astronomy.sun_elevation(self.time_in_seconds, &position) > 0.0,
+            CelestialBody::Polaris => !self.is_daylight(&position),
+        };
+        let kind = if conditions.precipitation >= OVERCAST_RAIN || !risen {
+            EventKind::NoSighting { body }
+        } else {
+            let skill = &mut self.player.skillset.navigation;
+            let (latitude_error, longitude_error) = navigation::sighting_error(&self.player.instruments, skill.get(), conditions.danger);
+            skill.gain_xp(SIGHTING_XP);
+
+            let latitude = (position.latitude() + self.rng.gen_range(-latitude_error..=latitude_error)).clamp(-FRAC_PI_2, FRAC_PI_2);
+            let longitude = longitude_error.map(|error| position.longitude() + self.rng.gen_range(-error..=error));
+            self.player.observe(Observation::Celestial { latitude, longitude }, self.globe.radius);
+            EventKind::Fix {
+                body,
+                longitude: longitude.is_some(),
+            }
+        };
+        self.events.push(Event {
+            time_in_seconds: self.time_in_seconds,
+            kind,
+        });
+    }
+}
@@ -0,0 +1,32 @@
+/// Kinds of goods that can be gathered from the land and sea and traded.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Good {
+    Fish,
+    Pearls,
+    Salt,
+    Furs,
+    Timber,
+    Grain,
+    Livestock,
+    Spices,
+    Ivory,
+    Ore,
+}
+
+impl std::fmt::Display for Good {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Good::Fish => "Fish",
+            Good::Pearls => "Pearls",
+            Good::Salt => "Salt",
+            Good::Furs => "Furs",
+            Good::Timber => "Timber",
+            Good::Grain => "Grain",
+            Good::Livestock => "Livestock",
+            Good::Spices => "Spices",
+            Good::Ivory => "Ivory",
+            Good::Ore => "Ore",
+        };
+        write!(f, "{}", name)
+    }
+}
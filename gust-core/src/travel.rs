@@ -0,0 +1,271 @@
+use std::f64::consts::TAU;
+
+use rand::Rng;
+
+use crate::{
+    event::{Event, EventKind},
+    people::{
+        navigation::{self, Observation, SIGHTING_RANGE},
+        task::Task,
+    },
+    world::{
+        globe::Surface,
+        pathfinding::{self, TravelMode},
+        position::{Course, Meters, Position},
+    },
+    Game,
+};
+
+/// Fatigue gained for each hour on the move, on foot and at sea.
+const WALKING_FATIGUE: f32 = 6.0;
+const SAILING_FATIGUE: f32 = 2.5;
+/// Fatigue at which a traveler is too tired to go on and has to rest.
+const EXHAUSTED: f32 = 100.0;
+/// Danger of the weather, from zero in fair weather to one in a hurricane, from which travelers on foot take shelter
+/// and ships heave to, drifting with the current.
+const SHELTER_DANGER: f32 = 0.5;
+/// Experience gained in traveling, and in sailing at sea, for each hour on the move.
+const TRAVELING_XP: f32 = 0.002;
+const SAILING_XP: f32 = 0.002;
+/// Number of bearings looked along for land in sight from the sea, and for the sea in sight from the land.
+const LOOKOUT_BEARINGS: usize = 8;
+/// Rain in mm per hour that hides the coast from ships at sea.
+const OBSCURING_RAIN: f32 = 4.0;
+/// Condition a ship loses each time it runs aground.
+const GROUNDING_DAMAGE: f32 = 0.05;
+
+impl Game {
+    /// Moves the player along their travels for a span of seconds, short enough for the daylight and the weather
+    /// to hold through it. Travelers on foot walk by day and stop to rest when exhausted. Ships sail day and night,
+    /// the crew sailing on while the captain rests, and drift with the current when they can't be sailed. Both
+    /// shelter from dangerous weather.
+    ///
+    /// The player steers from where they believe they are, and goes astray as they keep their heading only as well
+    /// as their skill lets them, and at sea as the current carries them along unseen, until what they see around
+    /// them sets them right. Once they believe they have arrived, the player's task is done.
+    pub(crate) fn progress_travel(&mut self, seconds: u64) {
+        let Task::Traveling { destination, course, route } = &self.player.task else {
+            return;
+        };
+        let (destination, course) = (*destination, *course);
+        let position = self.player.position;
+        let believed = self.player.believed_position;
+        let cell = &self.globe.cells[self.globe.cell_at(&position)];
+        let mode = match route {
+            Some(route) => route.mode,
+            None if cell.is_land() => TravelMode::Walking,
+            None => TravelMode::Sailing,
+        };
+        let target = route.as_ref().and_then(|route| route.waypoints.get(1)).copied().unwrap_or(destination);
+
+        let conditions = self.conditions_at(&position);
+        let hours = seconds as f32 / (60.0 * 60.0);
+        let sheltering = conditions.danger >= SHELTER_DANGER;
+        let resting = match mode {
+            TravelMode::Walking => !self.is_daylight(&position),
+            TravelMode::Sailing => false,
+        };
+
+        let exhausted = self.player.fatigue >= EXHAUSTED;
+        let mut adrift = false;
+        if mode == TravelMode::Sailing {
+            // The sea wears a ship down whether it sails or lies hove to.
+            adrift = self.player.ship.as_mut().is_none_or(|ship| {
+                ship.wear(hours, conditions.danger);
+                !ship.can_sail()
+            });
+        }
+
+        if sheltering || resting || adrift || exhausted && mode == TravelMode::Walking {
+            self.player.rest(hours);
+            if mode == TravelMode::Sailing {
+                let drifted = self.currents.drift(&self.globe, &position, seconds);
+                self.carry_ship(drifted, &destination);
+            }
+            self.look_around();
+            return;
+        }
+
+        let skillset = &mut self.player.skillset;
+        let (speed, fatigue, skill) = match mode {
+            TravelMode::Walking => {
+                let terrain = cell.biome.land_travel_cost().unwrap_or(1.0);
+                let skill = skillset.traveling.get();
+                (pathfinding::walking_speed(skill) / terrain, WALKING_FATIGUE, skill)
+            }
+            TravelMode::Sailing => {
+                let ship = self.player.ship.as_ref().expect("Travelers without a ship drift at sea");
+                let bearing = course.bearing(&believed, &target) as f32;
+                let skill = skillset.sailing.get();
+                let made_good = ship.sailing_polar(skill).made_good(bearing, conditions.wind);
+                skillset.sailing.gain_xp(SAILING_XP * hours);
+                // The fewer hands aboard, the more the captain has to do.
+                (made_good, SAILING_FATIGUE * ship.full_crew as f32 / ship.crew as f32, skill)
+            }
+        };
+        skillset.traveling.gain_xp(TRAVELING_XP * hours);
+        if exhausted {
+            self.player.rest(hours);
+        } else {
+            self.player.fatigue = (self.player.fatigue + fatigue * hours).min(EXHAUSTED);
+        }
+
+        let arrived = self.move_player(speed as Meters * seconds as Meters, course, &destination);
+        let reckoned = self.follow_reckoning(&believed, skill, mode, seconds);
+        match mode {
+            TravelMode::Walking => self.walk_to(reckoned, &destination),
+            TravelMode::Sailing => self.carry_ship(reckoned, &destination),
+        }
+        self.look_around();
+        if !matches!(self.player.task, Task::Traveling { .. }) {
+            return;
+        }
+
+        if arrived && mode == TravelMode::Sailing {
+            // Ships hold off the harbor until the tide lets them in, and give up on it if it never will.
+            let window = self.ship_tide_window(&destination);
+            if window.is_none_or(|until| until > self.time_in_seconds) {
+                self.player.position = position;
+                self.player.believed_position = believed;
+                match window {
+                    Some(until) => self.wait_for_tide(until, destination, course),
+                    None => {
+                        self.player.task = Task::Idle;
+                        self.events.push(Event {
+                            time_in_seconds: self.time_in_seconds,
+                            kind: EventKind::NoRoute { destination },
+                        });
+                    }
+                }
+                return;
+            }
+        }
+        if arrived {
+            self.player.task = Task::Idle;
+            self.events.push(Event {
+                time_in_seconds: self.time_in_seconds,
+                kind: EventKind::Arrived { destination },
+            });
+        }
+    }
+
+    /// Moves where the player believes they are a distance through the waypoints of their route in turn, or straight
+    /// along their course where they have none. Returns whether they believe they reached the destination.
+    fn move_player(&mut self, distance: Meters, course: Course, destination: &Position) -> bool {
+        let radius = self.globe.radius;
+        let position = &mut self.player.believed_position;
+        let Task::Traveling { route: Some(route), .. } = &mut self.player.task else {
+            course.step(position, distance, destination, radius);
+            return position == destination;
+        };
+
+        let mut left = distance;
+        while route.waypoints.len() > 1 {
+            left = course.step(position, left, &route.waypoints[1], radius);
+            if *position != route.waypoints[1] {
+                break;
+            }
+            route.waypoints.remove(0);
+        }
+
+        // The route ahead starts where the player is, and takes as much less time as it is shorter.
+        route.waypoints[0] = *position;
+        let remaining = route.waypoints.windows(2).map(|leg| leg[0].distance_to(&leg[1], radius)).sum();
+        if route.distance > 0.0 {
+            route.duration = (route.duration as f64 * remaining / route.distance) as u64;
+        }
+        route.distance = remaining;
+
+        route.waypoints.len() == 1
+    }
+
+    /// Where the player gets to going as far and in the direction they reckon they have come from where they believed
+    /// they were, off by the error in the heading they kept with their skill, and by the magnetic declination where they
+    /// are less what they allow for of it where they believe they are. At sea, the current carries them along as well,
+    /// unknown to them.
+    fn follow_reckoning(&mut self, from: &Position, skill: f32, mode: TravelMode, seconds: u64) -> Position {
+        let radius = self.globe.radius;
+        let believed = self.player.believed_position;
+        let mut position = self.player.position;
+
+        let distance = from.distance_to(&believed, radius);
+        if distance > 0.0 {
+            let error = navigation::steering_error(skill);
+            // Steering by compass, the needle points off true north by the declination where they are, whatever they
+            // make of it from where they believe they are.
+            let correction = navigation::declination_correction(self.player.skillset.navigation.get());
+            let deviation = self.magnetism.declination(&position) - correction * self.magnetism.declination(from);
+            let heading = from.initial_bearing_to(&believed) + deviation + self.rng.gen_range(-error..=error);
+            position = position.destination(heading, distance, radius);
+        }
+        if mode == TravelMode::Sailing {
+            position = self.currents.drift(&self.globe, &position, seconds);
+        }
+        position
+    }
+
+    /// Walks the player to a position, unless it lies off the land. Then they stop at the water's edge and give up on
+    /// their travels, as the way doesn't go where they thought.
+    fn walk_to(&mut self, to: Position, destination: &Position) {
+        if self.globe.cells[self.globe.cell_at(&to)].is_land() {
+            self.player.position = to;
+            return;
+        }
+
+        self.player.task = Task::Idle;
+        self.events.push(Event {
+            time_in_seconds: self.time_in_seconds,
+            kind: EventKind::StoppedAtWater { destination: *destination },
+        });
+    }
+
+    /// Carries the player's ship to a position, unless it lies on land other than where they set out from and make
+    /// for. Then they run aground on the shore, damaging the ship, and give up on the voyage knowing at least that
+    /// they are off a coast.
+    pub(crate) fn carry_ship(&mut self, to: Position, destination: &Position) {
+        let cell = self.globe.cell_at(&to);
+        let ports = [self.globe.cell_at(&self.player.position), self.globe.cell_at(destination)];
+        if !self.globe.cells[cell].is_land() || ports.contains(&cell) {
+            self.player.position = to;
+            return;
+        }
+
+        if let Some(ship) = &mut self.player.ship {
+            ship.damage(GROUNDING_DAMAGE);
+        }
+        self.player.observe(Observation::Coastline, self.globe.radius);
+        self.player.task = Task::Idle;
+        self.events.push(Event {
+            time_in_seconds: self.time_in_seconds,
+            kind: EventKind::RanAground,
+        });
+    }
+
+    /// Sets the player right by day with a landmark in sight on land, or with a coast in sight at sea unless rain
+    /// hides it.
+    fn look_around(&mut self) {
+        let position = self.player.position;
+        let radius = self.globe.radius;
+        if !self.is_daylight(&position) {
+            return;
+        }
+
+        let in_sight = |surface: Surface| {
+            (0..LOOKOUT_BEARINGS).any(|bearing| {
+                let point = position.destination(bearing as f64 * TAU / LOOKOUT_BEARINGS as f64, SIGHTING_RANGE, radius);
+                self.globe.cells[self.globe.cell_at(&point)].surface == surface
+            })
+        };
+        let cell = &self.globe.cells[self.globe.cell_at(&position)];
+        if cell.is_land() {
+            // Away from rivers, coasts and settlements, only the skilled recognize the lie of the land.
+            let landmark = cell.river || !self.settlements.within(&position, SIGHTING_RANGE, radius).is_empty() || in_sight(Surface::Ocean);
+            let chance = navigation::landmark_chance(self.player.skillset.navigation.get());
+            if landmark || self.rng.gen_bool(chance) {
+                self.player.observe(Observation::Landmark, radius);
+            }
+        } else if self.conditions_at(&position).precipitation < OBSCURING_RAIN && in_sight(Surface::Land) {
+            self.player.observe(Observation::Coastline, radius);
+        }
+    }
+}
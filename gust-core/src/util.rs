@@ -8,4 +8,5 @@ impl Fractionable for u16 {
     }
 }
 
+pub mod noise;
 pub mod stack;
@@ -0,0 +1,97 @@
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+/// Gradient directions used by the noise, the midpoints of the edges of a cube.
+const GRADIENTS: [[f32; 3]; 12] = [
+    [1.0, 1.0, 0.0],
+    [-1.0, 1.0, 0.0],
+    [1.0, -1.0, 0.0],
+    [-1.0, -1.0, 0.0],
+    [1.0, 0.0, 1.0],
+    [-1.0, 0.0, 1.0],
+    [1.0, 0.0, -1.0],
+    [-1.0, 0.0, -1.0],
+    [0.0, 1.0, 1.0],
+    [0.0, -1.0, 1.0],
+    [0.0, 1.0, -1.0],
+    [0.0, -1.0, -1.0],
+];
+
+/// Seeded three dimensional gradient noise, as described by Ken Perlin.
+/// Two instances built from the same seed produce identical values.
+pub struct Perlin {
+    permutation: [u8; 512],
+}
+
+impl Perlin {
+    pub fn new(seed: u64) -> Self {
+        let mut table: Vec<u8> = (0..=255).collect();
+        table.shuffle(&mut StdRng::seed_from_u64(seed));
+
+        let mut permutation = [0; 512];
+        for (index, value) in permutation.iter_mut().enumerate() {
+            *value = table[index % 256];
+        }
+
+        Self { permutation }
+    }
+
+    /// Samples the noise at the given point. The result is roughly in the range [-1, 1].
+    pub fn get(&self, x: f32, y: f32, z: f32) -> f32 {
+        let (xi, yi, zi) = (x.floor(), y.floor(), z.floor());
+        let (xf, yf, zf) = (x - xi, y - yi, z - zi);
+        let (xi, yi, zi) = (xi as i32 & 255, yi as i32 & 255, zi as i32 & 255);
+
+        let (u, v, w) = (fade(xf), fade(yf), fade(zf));
+
+        let corner = |dx: i32, dy: i32, dz: i32| {
+            let hash = self.hash(xi + dx, yi + dy, zi + dz);
+            let gradient = GRADIENTS[hash as usize % GRADIENTS.len()];
+            gradient[0] * (xf - dx as f32) + gradient[1] * (yf - dy as f32) + gradient[2] * (zf - dz as f32)
+        };
+
+        let x00 = lerp(corner(0, 0, 0), corner(1, 0, 0), u);
+        let x10 = lerp(corner(0, 1, 0), corner(1, 1, 0), u);
+        let x01 = lerp(corner(0, 0, 1), corner(1, 0, 1), u);
+        let x11 = lerp(corner(0, 1, 1), corner(1, 1, 1), u);
+
+        lerp(lerp(x00, x10, v), lerp(x01, x11, v), w)
+    }
+
+    /// Sums several octaves of noise, each one with `lacunarity` times the frequency
+    /// and `persistence` times the amplitude of the previous one.
+    /// The result is normalized back into roughly [-1, 1].
+    pub fn fractal(&self, x: f32, y: f32, z: f32, octaves: u32, lacunarity: f32, persistence: f32) -> f32 {
+        let mut sum = 0.0;
+        let mut frequency = 1.0;
+        let mut amplitude = 1.0;
+        let mut total_amplitude = 0.0;
+
+        for octave in 0..octaves {
+            // Offset each octave so that the lattice points of different octaves don't line up
+            let offset = octave as f32 * 17.31;
+            sum += amplitude * self.get(x * frequency + offset, y * frequency + offset, z * frequency + offset);
+            total_amplitude += amplitude;
+            frequency *= lacunarity;
+            amplitude *= persistence;
+        }
+
+        if total_amplitude == 0.0 {
+            0.0
+        } else {
+            sum / total_amplitude
+        }
+    }
+
+    fn hash(&self, x: i32, y: i32, z: i32) -> u8 {
+        let p = &self.permutation;
+        p[p[p[x as usize] as usize + y as usize] as usize + z as usize]
+    }
+}
+
+fn fade(t: f32) -> f32 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+fn lerp(a: f32, b: f32, t: f32) -> f32 {
+    a + t * (b - a)
+}
@@ -15,3 +15,9 @@ impl<T> Stack<T> {
         self.data.pop().unwrap()
     }
 }
+
+impl<T> Default for Stack<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,2 +1,18 @@
+pub mod astronomy;
+pub mod biome;
+pub mod climate;
+pub mod currents;
+pub mod forecast;
 pub mod globe;
+pub mod hydrology;
+pub mod isochrone;
+pub mod location;
+pub mod magnetism;
+pub mod pathfinding;
 pub mod position;
+pub mod settlement;
+pub mod tectonics;
+pub mod tides;
+pub mod topology;
+pub mod weather;
+pub mod wind;
@@ -0,0 +1,205 @@
+use std::f64::consts::{FRAC_PI_2, PI, TAU};
+
+use glam::Vec3A;
+
+use super::position::Position;
+
+/// Mean power of sunlight reaching the top of the atmosphere, in W/m², facing the sun.
+const SOLAR_CONSTANT: f64 = 1361.0;
+/// Elevation of the center of the sun at sunrise and sunset, in radians. It sits just below the horizon,
+/// as the atmosphere bends its light and its upper edge shows first.
+const SUNRISE_ELEVATION: f64 = -0.833 * PI / 180.0;
+
+/// The motion of the globe around its sun, which sets the seasons and the days.
+///
+/// Game time starts at midnight on the prime meridian, on the day of the March equinox, at new moon.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Astronomy {
+    /// Tilt of the axis of the globe relative to its orbit, in radians.
+    pub axial_tilt: f64,
+    /// Seconds in a year, from one March equinox to the next.
+    pub year_length: u64,
+    /// Seconds in a day, from one noon to the next.
+    pub day_length: u64,
+    /// Seconds in a lunar month, from one new moon to the next.
+    pub lunar_month: u64,
+}
+
+impl Default for Astronomy {
+    fn default() -> Self {
+        Self {
+            axial_tilt: 23.44_f64.to_radians(),
+            year_length: 31_557_600,
+            day_length: 86_400,
+            lunar_month: 2_551_443,
+        }
+    }
+}
+
+/// The four seasons, as they are in the hemisphere of some position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl std::fmt::Display for Season {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Season::Spring => "Spring",
+            Season::Summer => "Summer",
+            Season::Autumn => "Autumn",
+            Season::Winter => "Winter",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// When the sun rises and sets at some place on some day.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SunCycle {
+    /// Sunrise and sunset, in seconds after local midnight.
+    Daily { sunrise: u64, sunset: u64 },
+    /// The midnight sun of polar summers, which never sets.
+    PolarDay,
+    /// The long night of polar winters, when the sun never rises.
+    PolarNight,
+}
+
+impl Astronomy {
+    /// Fraction of the year gone by since the last March equinox, in [0, 1).
+    pub fn year_fraction(&self, time_in_seconds: u64) -> f64 {
+        (time_in_seconds % self.year_length) as f64 / self.year_length as f64
+    }
+
+    /// Latitude in radians the sun stands straight overhead at noon, swinging between the tropics over the year.
+    pub fn sun_declination(&self, time_in_seconds: u64) -> f64 {
+        (self.axial_tilt.sin() * (TAU * self.year_fraction(time_in_seconds)).sin()).asin()
+    }
+
+    /// Seconds after local midnight by the sun at a position, from its longitude.
+    pub fn local_solar_time(&self, time_in_seconds: u64, position: &Position) -> u64 {
+        let offset = position.normalized().longitude() / TAU * self.day_length as f64;
+        (time_in_seconds as f64 + offset).rem_euclid(self.day_length as f64) as u64 % self.day_length
+    }
+
+    /// The point on the globe where the sun stands straight overhead.
+    pub fn subsolar_point(&self, time_in_seconds: u64) -> Position {
+        let day_fraction = (time_in_seconds % self.day_length) as f64 / self.day_length as f64;
+        Position::new(self.sun_declination(time_in_seconds), TAU * (0.5 - day_fraction)).normalized()
+    }
+
+    /// Unit vector from the center of the globe towards the sun.
+    pub fn sun_direction(&self, time_in_seconds: u64) -> Vec3A {
+        self.subsolar_point(time_in_seconds).to_unit_vector()
+    }
+
+    /// Fraction of the lunar month gone by since the last new moon, in [0, 1). The moon is full at one half.
+    pub fn moon_phase(&self, time_in_seconds: u64) -> f64 {
+        (time_in_seconds % self.lunar_month) as f64 / self.lunar_month as f64
+    }
+
+    /// The point on the globe where the moon stands straight overhead. The moon is taken to travel along
+    /// the path of the sun across the sky, pulling ahead of it by a full turn each lunar month.
+    pub fn sublunar_point(&self, time_in_seconds: u64) -> Position {
+        let elongation = TAU * self.moon_phase(time_in_seconds);
+        let ecliptic_longitude = TAU * self.year_fraction(time_in_seconds) + elongation;
+        let declination = (self.axial_tilt.sin() * ecliptic_longitude.sin()).asin();
+        let longitude = self.subsolar_point(time_in_seconds).longitude() - elongation;
+
+        Position::new(declination, longitude).normalized()
+    }
+
+    /// Angle in radians of the sun above the horizon at a position, negative at night.
+    pub fn sun_elevation(&self, time_in_seconds: u64, position: &Position) -> f64 {
+        let declination = self.sun_declination(time_in_seconds);
+        let hour_angle = self.hour_angle(time_in_seconds, position);
+        let latitude = position.latitude();
+
+        (latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * hour_angle.cos())
+            .clamp(-1.0, 1.0)
+            .asin()
+    }
+
+    /// Whether any of the sun shows above the horizon at a position.
+    pub fn is_daylight(&self, time_in_seconds: u64, position: &Position) -> bool {
+        self.sun_elevation(time_in_seconds, position) > SUNRISE_ELEVATION
+    }
+
+    /// When the sun rises and sets at a position on the day of the given moment.
+    pub fn sun_cycle(&self, time_in_seconds: u64, position: &Position) -> SunCycle {
+        match self.half_day_angle(time_in_seconds, position, SUNRISE_ELEVATION) {
+            None if self.sun_elevation_at_noon(time_in_seconds, position) > SUNRISE_ELEVATION => SunCycle::PolarDay,
+            None => SunCycle::PolarNight,
+            Some(half_day) => {
+                let half_day_seconds = half_day / TAU * self.day_length as f64;
+                let noon = self.day_length as f64 / 2.0;
+                SunCycle::Daily {
+                    sunrise: (noon - half_day_seconds) as u64,
+                    sunset: (noon + half_day_seconds) as u64,
+                }
+            }
+        }
+    }
+
+    /// Seconds of daylight at a position on the day of the given moment.
+    pub fn daylight_duration(&self, time_in_seconds: u64, position: &Position) -> u64 {
+        match self.sun_cycle(time_in_seconds, position) {
+            SunCycle::Daily { sunrise, sunset } => sunset - sunrise,
+            SunCycle::PolarDay => self.day_length,
+            SunCycle::PolarNight => 0,
+        }
+    }
+
+    /// The astronomical season at a position, with the seasons of the southern hemisphere
+    /// opposite those of the northern.
+    pub fn season(&self, time_in_seconds: u64, position: &Position) -> Season {
+        let mut quarter = (self.year_fraction(time_in_seconds) * 4.0) as usize;
+        if position.latitude() < 0.0 {
+            quarter += 2;
+        }
+
+        match quarter % 4 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    /// Mean power of sunlight over the day of the given moment reaching the top of the atmosphere
+    /// at a latitude in radians, in W/m² of level ground.
+    pub fn daily_insolation(&self, time_in_seconds: u64, latitude: f64) -> f64 {
+        let declination = self.sun_declination(time_in_seconds);
+        let position = Position::new(latitude, 0.0);
+        let half_day = match self.half_day_angle(time_in_seconds, &position, 0.0) {
+            Some(half_day) => half_day,
+            None if self.sun_elevation_at_noon(time_in_seconds, &position) > 0.0 => PI,
+            None => return 0.0,
+        };
+
+        SOLAR_CONSTANT / PI * (half_day * latitude.sin() * declination.sin() + latitude.cos() * declination.cos() * half_day.sin())
+    }
+
+    /// Angle in radians the globe turns through from the sun crossing the local meridian at noon to the given moment.
+    fn hour_angle(&self, time_in_seconds: u64, position: &Position) -> f64 {
+        let day_fraction = self.local_solar_time(time_in_seconds, position) as f64 / self.day_length as f64;
+        TAU * (day_fraction - 0.5)
+    }
+
+    fn sun_elevation_at_noon(&self, time_in_seconds: u64, position: &Position) -> f64 {
+        FRAC_PI_2 - (position.latitude() - self.sun_declination(time_in_seconds)).abs()
+    }
+
+    /// Hour angle in radians of the sun setting to the given elevation, or `None` if it stays above or below
+    /// that elevation all day.
+    fn half_day_angle(&self, time_in_seconds: u64, position: &Position, elevation: f64) -> Option<f64> {
+        let declination = self.sun_declination(time_in_seconds);
+        let latitude = position.latitude();
+
+        let cosine = (elevation.sin() - latitude.sin() * declination.sin()) / (latitude.cos() * declination.cos());
+        (-1.0..=1.0).contains(&cosine).then(|| cosine.acos())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::trade::good::Good;
+
+use super::globe::{Cell, Globe, Surface};
+
+/// Sea floor shallower than this, in meters, is a shallow sea rather than the open ocean.
+const SHELF_DEPTH: f32 = -600.0;
+/// Land higher than this, in meters, is mountains whatever its climate.
+const MOUNTAIN_ELEVATION: f32 = 2500.0;
+
+/// The kind of landscape or seascape covering a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Biome {
+    Ocean,
+    ShallowSea,
+    Reef,
+    PackIce,
+    Lake,
+    IceSheet,
+    Tundra,
+    Taiga,
+    TemperateForest,
+    Grassland,
+    Desert,
+    Savanna,
+    Jungle,
+    Mountains,
+}
+
+impl Biome {
+    /// Classifies a cell by its surface, elevation and mean climate, following Whittaker's biome diagram.
+    pub fn classify(cell: &Cell) -> Biome {
+        let temperature = cell.mean_temperature;
+        let precipitation = cell.annual_precipitation;
+
+        match cell.surface {
+            Surface::Ocean if temperature < -8.0 => Biome::PackIce,
+            Surface::Ocean if cell.elevation > SHELF_DEPTH && temperature >= 20.0 => Biome::Reef,
+            Surface::Ocean if cell.elevation > SHELF_DEPTH => Biome::ShallowSea,
+            Surface::Ocean => Biome::Ocean,
+            Surface::Lake => Biome::Lake,
+            Surface::Land if cell.elevation > MOUNTAIN_ELEVATION => Biome::Mountains,
+            Surface::Land if temperature < -10.0 => Biome::IceSheet,
+            Surface::Land if temperature < 0.0 => Biome::Tundra,
+            Surface::Land if temperature < 5.0 => {
+                if precipitation < 300.0 {
+                    Biome::Tundra
+                } else {
+                    Biome::Taiga
+                }
+            }
+            Surface::Land if temperature < 20.0 => {
+                if precipitation < 250.0 {
+                    Biome::Desert
+                } else if precipitation < 600.0 {
+                    Biome::Grassland
+                } else {
+                    Biome::TemperateForest
+                }
+            }
+            Surface::Land => {
+                if precipitation < 300.0 {
+                    Biome::Desert
+                } else if precipitation < 1200.0 {
+                    Biome::Savanna
+                } else {
+                    Biome::Jungle
+                }
+            }
+        }
+    }
+
+    pub fn is_water(&self) -> bool {
+        matches!(self, Biome::Ocean | Biome::ShallowSea | Biome::Reef | Biome::PackIce | Biome::Lake)
+    }
+
+    /// How many times longer it takes to cross this biome on foot than open grassland.
+    /// `None` for water, which can't be crossed on foot.
+    pub fn land_travel_cost(&self) -> Option<f32> {
+        let cost = match self {
+            Biome::Ocean | Biome::ShallowSea | Biome::Reef | Biome::PackIce | Biome::Lake => return None,
+            Biome::Grassland => 1.0,
+            Biome::Savanna => 1.1,
+            Biome::TemperateForest => 1.4,
+            Biome::Tundra => 1.5,
+            Biome::Taiga => 1.6,
+            Biome::Desert => 1.8,
+            Biome::Jungle => 2.5,
+            Biome::Mountains => 3.0,
+            Biome::IceSheet => 3.5,
+        };
+        Some(cost)
+    }
+
+    /// How well this biome feeds the people settled on it, from zero for none at all to one for the best farmland.
+    pub fn fertility(&self) -> f32 {
+        match self {
+            Biome::Ocean | Biome::ShallowSea | Biome::Reef | Biome::PackIce | Biome::Lake | Biome::IceSheet => 0.0,
+            Biome::Grassland => 1.0,
+            Biome::TemperateForest => 0.8,
+            Biome::Savanna => 0.7,
+            Biome::Jungle => 0.5,
+            Biome::Taiga => 0.3,
+            Biome::Tundra => 0.1,
+            Biome::Mountains => 0.1,
+            Biome::Desert => 0.05,
+        }
+    }
+
+    /// Goods that can be gathered in this biome.
+    pub fn goods(&self) -> &'static [Good] {
+        match self {
+            Biome::Ocean => &[Good::Fish],
+            Biome::ShallowSea => &[Good::Fish, Good::Salt],
+            Biome::Reef => &[Good::Fish, Good::Pearls],
+            Biome::PackIce => &[Good::Furs],
+            Biome::Lake => &[Good::Fish],
+            Biome::IceSheet => &[],
+            Biome::Tundra => &[Good::Furs],
+            Biome::Taiga => &[Good::Furs, Good::Timber],
+            Biome::TemperateForest => &[Good::Timber, Good::Grain],
+            Biome::Grassland => &[Good::Grain, Good::Livestock],
+            Biome::Desert => &[Good::Salt],
+            Biome::Savanna => &[Good::Livestock, Good::Ivory],
+            Biome::Jungle => &[Good::Spices, Good::Timber],
+            Biome::Mountains => &[Good::Ore],
+        }
+    }
+}
+
+impl std::fmt::Display for Biome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Biome::Ocean => "Ocean",
+            Biome::ShallowSea => "Shallow sea",
+            Biome::Reef => "Reef",
+            Biome::PackIce => "Pack ice",
+            Biome::Lake => "Lake",
+            Biome::IceSheet => "Ice sheet",
+            Biome::Tundra => "Tundra",
+            Biome::Taiga => "Taiga",
+            Biome::TemperateForest => "Temperate forest",
+            Biome::Grassland => "Grassland",
+            Biome::Desert => "Desert",
+            Biome::Savanna => "Savanna",
+            Biome::Jungle => "Jungle",
+            Biome::Mountains => "Mountains",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+impl Globe {
+    /// Classifies the biome of every cell. Expects the mean climate to have been computed.
+    pub fn classify_biomes(&mut self) {
+        for cell in &mut self.cells {
+            cell.biome = Biome::classify(cell);
+        }
+    }
+}
@@ -0,0 +1,262 @@
+use std::collections::VecDeque;
+
+use glam::Vec3A;
+
+use super::{
+    globe::{Globe, Surface},
+    position::Position,
+    wind::{self, WindField},
+};
+
+/// Mean annual temperature at sea level on the equator, in °C.
+const EQUATOR_TEMPERATURE: f32 = 27.0;
+/// Mean annual temperature at sea level at the poles, in °C.
+const POLE_TEMPERATURE: f32 = -22.0;
+/// Drop in temperature per meter of elevation, in °C.
+const LAPSE_RATE: f32 = 0.0065;
+/// Fraction of its moisture the air keeps over each cell of land it crosses away from the sea.
+const INLAND_MOISTURE_RETENTION: f32 = 0.78;
+/// Fraction of the mildness of the sea the air keeps over each cell of land it crosses.
+const MARITIME_RETENTION: f32 = 0.75;
+/// Meters the air has to climb over the land to lose about two thirds of its moisture as rain on the way up.
+const RAIN_SHADOW_HEIGHT: f32 = 2000.0;
+/// Meters the wind has to climb onto a cell for the rain falling on it to double.
+const OROGRAPHIC_HEIGHT: f32 = 1000.0;
+/// Most cells of land the wind is traced back over to find the sea it brought its moisture from.
+const MAX_FETCH: usize = 12;
+/// Wind in m/s below which it is too weak and fickle to carry the air of the sea in any one direction.
+const STEADY_WIND: f32 = 1.0;
+
+/// Change of temperature in °C for each W/m² the sunshine is above or below its yearly mean,
+/// over the open sea and deep inside a continent. The sea stores heat and evens out the seasons.
+const OCEAN_SENSITIVITY: f32 = 0.025;
+const LAND_SENSITIVITY: f32 = 0.11;
+/// Fraction of a year the temperature lags behind the sunshine, over the open sea and deep inside a continent.
+const OCEAN_LAG: f32 = 1.0 / 6.0;
+const LAND_LAG: f32 = 1.0 / 12.0;
+/// Temperature in °C at which sea water freezes.
+const SEA_FREEZING_POINT: f32 = -1.8;
+/// Number of moments over the year the climate is sampled at to work out its yearly means.
+const SAMPLES_PER_YEAR: u64 = 12;
+
+/// How the air reaches a cell of land from the sea, following the prevailing wind back upwind.
+struct AirPath {
+    /// Fraction of the moisture of the sea the air still carries.
+    moisture: f32,
+    /// Number of cells of land the air has crossed since leaving the sea.
+    fetch: u32,
+    /// Meters the air climbs from the last cell onto this one.
+    final_rise: f32,
+}
+
+impl Globe {
+    /// Works out the temperature, precipitation and sea ice of every cell at the moment of a wind field, from its
+    /// latitude, its elevation, the season, and the air the prevailing winds bring it from the sea.
+    pub fn update_climate(&mut self, wind: &WindField) {
+        let time_in_seconds = wind.time_in_seconds;
+        let distances = self.distances_from_water();
+        let shift = wind::seasonal_shift(self, time_in_seconds);
+
+        for index in 0..self.cells.len() {
+            let latitude = self.position_of(index).latitude() as f32;
+            let path = self.air_path(index, wind, &distances);
+
+            let continentality = match self.cells[index].surface {
+                Surface::Land => 1.0 - MARITIME_RETENTION.powi(path.fetch as i32),
+                Surface::Ocean | Surface::Lake => 0.0,
+            };
+            let sensitivity = OCEAN_SENSITIVITY + (LAND_SENSITIVITY - OCEAN_SENSITIVITY) * continentality;
+            let lag = OCEAN_LAG + (LAND_LAG - OCEAN_LAG) * continentality;
+            let lag_seconds = (lag * self.astronomy.year_length as f32) as u64;
+            let lagged_time = time_in_seconds + self.astronomy.year_length - lag_seconds;
+            let insolation = self.astronomy.daily_insolation(lagged_time, latitude as f64) as f32;
+            let orographic = 1.0 + (path.final_rise / OROGRAPHIC_HEIGHT).clamp(0.0, 2.0);
+
+            let cell = &mut self.cells[index];
+            let land_elevation = cell.elevation.max(0.0);
+            cell.temperature = sea_level_temperature(latitude) + sensitivity * (insolation - cell.mean_insolation) - LAPSE_RATE * land_elevation;
+            cell.precipitation = latitude_precipitation(latitude - shift) * path.moisture * orographic;
+            cell.sea_ice = cell.surface == Surface::Ocean && cell.temperature < SEA_FREEZING_POINT;
+        }
+
+        self.climate_time_in_seconds = time_in_seconds;
+    }
+
+    /// Works out the mean sunshine, temperature and total precipitation of every cell over a year,
+    /// by sampling its climate through the seasons, then leaves the climate as it is at the start of the game.
+    pub fn compute_mean_climate(&mut self) {
+        let mut temperatures = vec![0.0; self.cells.len()];
+        let mut precipitations = vec![0.0; self.cells.len()];
+        let mean_insolation = self.mean_insolation_by_cell();
+        for (cell, mean_insolation) in self.cells.iter_mut().zip(mean_insolation) {
+            cell.mean_insolation = mean_insolation;
+        }
+
+        let winds: Vec<WindField> = (0..SAMPLES_PER_YEAR)
+            .map(|sample| WindField::compute(self, self.astronomy.year_length * sample / SAMPLES_PER_YEAR))
+            .collect();
+        for wind in &winds {
+            self.update_climate(wind);
+            for (index, cell) in self.cells.iter().enumerate() {
+                temperatures[index] += cell.temperature / SAMPLES_PER_YEAR as f32;
+                precipitations[index] += cell.precipitation / SAMPLES_PER_YEAR as f32;
+            }
+        }
+
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            cell.mean_temperature = temperatures[index];
+            cell.annual_precipitation = precipitations[index];
+        }
+
+        self.update_climate(&winds[0]);
+    }
+
+    /// Current temperature at a position, in °C, blended from the cells around it.
+    pub fn temperature_at(&self, position: &Position) -> f32 {
+        self.locate(position).interpolate(|cell| self.cells[cell].temperature)
+    }
+
+    /// Current rate of precipitation at a position, in millimeters per year, blended from the cells around it.
+    pub fn precipitation_at(&self, position: &Position) -> f32 {
+        self.locate(position).interpolate(|cell| self.cells[cell].precipitation)
+    }
+
+    /// Whether the sea is frozen over at a position.
+    pub fn is_sea_ice_at(&self, position: &Position) -> bool {
+        self.cells[self.cell_at(position)].sea_ice
+    }
+
+    /// Number of cells between each cell and the nearest sea or lake, zero for water cells.
+    pub(crate) fn distances_from_water(&self) -> Vec<u32> {
+        let mut distances = vec![u32::MAX; self.cells.len()];
+        let mut queue = VecDeque::new();
+
+        for (index, cell) in self.cells.iter().enumerate() {
+            if cell.surface != Surface::Land {
+                distances[index] = 0;
+                queue.push_back(index);
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            for &neighbor in self.topology.neighbors(cell) {
+                if distances[neighbor] == u32::MAX {
+                    distances[neighbor] = distances[cell] + 1;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        distances
+    }
+
+    /// Mean sunshine over the year at the latitude of each cell, in W/m².
+    fn mean_insolation_by_cell(&self) -> Vec<f32> {
+        let year_length = self.astronomy.year_length;
+        (0..self.cells.len())
+            .map(|cell| {
+                let latitude = self.position_of(cell).latitude();
+                let total: f64 = (0..SAMPLES_PER_YEAR)
+                    .map(|sample| self.astronomy.daily_insolation(year_length * sample / SAMPLES_PER_YEAR, latitude))
+                    .sum();
+                (total / SAMPLES_PER_YEAR as f64) as f32
+            })
+            .collect()
+    }
+
+    /// Follows the wind back upwind from a cell to the sea, then forward again to the cell, drying out the air
+    /// over each cell of land it crosses and wringing out its moisture as rain over the mountains it climbs.
+    /// Where the wind is too weak to follow, the air comes from the nearest water in any direction.
+    fn air_path(&self, cell: usize, wind: &WindField, distances: &[u32]) -> AirPath {
+        if self.cells[cell].surface != Surface::Land {
+            return AirPath {
+                moisture: 1.0,
+                fetch: 0,
+                final_rise: 0.0,
+            };
+        }
+
+        let mut path = vec![cell];
+        let mut current = cell;
+        while self.cells[current].surface == Surface::Land && path.len() <= MAX_FETCH {
+            match self.upwind_neighbor(current, wind) {
+                Some(next) if !path.contains(&next) => {
+                    path.push(next);
+                    current = next;
+                }
+                _ => break,
+            }
+        }
+
+        let source = *path.last().unwrap();
+        if path.len() == 1 || self.cells[source].surface == Surface::Land && distances[source] >= distances[cell] {
+            // The wind brings no air from nearer the sea than the cell already is.
+            let distance = distances[cell];
+            return AirPath {
+                moisture: INLAND_MOISTURE_RETENTION.powi(distance as i32),
+                fetch: distance,
+                final_rise: 0.0,
+            };
+        }
+
+        let mut moisture = match self.cells[source].surface {
+            Surface::Land => INLAND_MOISTURE_RETENTION.powi(distances[source] as i32),
+            Surface::Ocean | Surface::Lake => 1.0,
+        };
+        let mut fetch = if self.cells[source].is_land() { distances[source] } else { 0 };
+        let mut final_rise = 0.0;
+
+        // Downwind from the source back to the cell
+        for step in path.windows(2).rev() {
+            let (to, from) = (step[0], step[1]);
+            let rise = (self.cells[to].elevation.max(0.0) - self.cells[from].elevation.max(0.0)).max(0.0);
+            moisture *= INLAND_MOISTURE_RETENTION;
+            fetch += 1;
+
+            // The rain of the climb onto the cell itself falls on the cell, rather than being lost upwind of it.
+            if to == cell {
+                final_rise = rise;
+            } else {
+                moisture *= (-rise / RAIN_SHADOW_HEIGHT).exp();
+            }
+        }
+
+        AirPath { moisture, fetch, final_rise }
+    }
+
+    /// The neighbor of a cell the prevailing wind blows in from, if the wind is steady enough to tell.
+    fn upwind_neighbor(&self, cell: usize, wind: &WindField) -> Option<usize> {
+        let local_wind = wind.cell(cell);
+        if local_wind.speed() < STEADY_WIND {
+            return None;
+        }
+
+        let (east, north) = self.position_of(cell).local_axes();
+        let upwind: Vec3A = -(east * local_wind.east + north * local_wind.north).normalize();
+        let center = self.topology.centroid(cell);
+
+        self.topology
+            .neighbors(cell)
+            .iter()
+            .map(|&neighbor| (neighbor, (self.topology.centroid(neighbor) - center).normalize().dot(upwind)))
+            .filter(|(_, alignment)| *alignment > 0.5)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(neighbor, _)| neighbor)
+    }
+}
+
+/// Mean annual temperature at sea level for a latitude in radians, in °C.
+pub fn sea_level_temperature(latitude: f32) -> f32 {
+    // Rounding leaves the cosine just below zero at the poles, where a fractional power of it would be NaN.
+    POLE_TEMPERATURE + (EQUATOR_TEMPERATURE - POLE_TEMPERATURE) * latitude.cos().max(0.0).powf(1.3)
+}
+
+/// Annual precipitation over the open sea for a latitude in radians, in millimeters.
+/// Rain is heaviest where the trade winds meet at the equator and where the westerlies meet the
+/// polar air in the mid-latitudes, and lightest under the dry, sinking air of the subtropics and the poles.
+pub fn latitude_precipitation(latitude: f32) -> f32 {
+    let degrees = latitude.to_degrees().abs();
+    let band = |center: f32, width: f32| (-((degrees - center) / width).powi(2)).exp();
+
+    350.0 + 2200.0 * band(0.0, 12.0) + 900.0 * band(52.0, 14.0) - 200.0 * band(28.0, 8.0)
+}
@@ -0,0 +1,176 @@
+use glam::{Vec2, Vec3A};
+
+use super::{
+    globe::{Globe, Surface},
+    position::{Meters, Position},
+    wind::WindField,
+};
+
+/// Speed of the surface current as a fraction of the speed of the wind driving it.
+const WIND_DRAG: f32 = 0.03;
+/// Angle in radians the surface current turns from the wind, to the right in the northern hemisphere
+/// and to the left in the southern, under the spin of the globe.
+const EKMAN_ANGLE: f32 = 20.0 * std::f32::consts::PI / 180.0;
+/// Factor the currents along a coast run faster by, squeezed between the open sea and the shore.
+const BOUNDARY_SPEEDUP: f32 = 1.5;
+/// Number of times the currents are blended with those of the neighboring cells, so that the deflection
+/// along the coasts spreads out into the open sea and the currents close into gyres.
+const SMOOTHING_PASSES: usize = 4;
+
+/// Velocity of the water at the surface of the sea, in m/s.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Current {
+    /// Speed towards the east. Negative when flowing towards the west.
+    pub east: f32,
+    /// Speed towards the north. Negative when flowing towards the south.
+    pub north: f32,
+}
+
+impl Current {
+    pub fn new(east: f32, north: f32) -> Self {
+        Self { east, north }
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.east.hypot(self.north)
+    }
+
+    /// Direction in radians, clockwise from north, that the current flows towards, as currents are named.
+    /// Unlike winds, a northerly current flows towards the north.
+    pub fn direction(&self) -> f32 {
+        self.east.atan2(self.north).rem_euclid(std::f32::consts::TAU)
+    }
+
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(self.east, self.north)
+    }
+
+    pub fn from_vec2(vector: Vec2) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+/// The surface current over each cell of a globe at some moment. Land and lakes have none.
+pub struct CurrentField {
+    currents: Vec<Current>,
+    /// Game time in seconds the field was computed for.
+    pub time_in_seconds: u64,
+}
+
+impl CurrentField {
+    /// Drives the currents with the wind, then turns them aside along the coasts. Blocked by a coast,
+    /// the water turns the way the spin of the globe pushes it, which closes the currents into gyres with
+    /// strong currents running along the edges of the oceans.
+    pub fn compute(globe: &Globe, wind: &WindField) -> Self {
+        let cell_count = globe.cells.len();
+        let is_ocean = |cell: usize| globe.cells[cell].surface == Surface::Ocean;
+
+        let mut currents: Vec<Vec2> = (0..cell_count)
+            .map(|cell| {
+                if !is_ocean(cell) {
+                    return Vec2::ZERO;
+                }
+                let turning = -globe.position_of(cell).latitude().signum() as f32 * EKMAN_ANGLE;
+                Vec2::from_angle(turning).rotate(wind.cell(cell).as_vec2() * WIND_DRAG)
+            })
+            .collect();
+
+        let coasts: Vec<Option<Vec2>> = (0..cell_count).map(|cell| coast_normal(globe, cell)).collect();
+
+        for _ in 0..=SMOOTHING_PASSES {
+            for cell in 0..cell_count {
+                if let Some(normal) = coasts[cell] {
+                    let latitude = globe.position_of(cell).latitude();
+                    currents[cell] = deflect(currents[cell], normal, latitude);
+                }
+            }
+
+            currents = (0..cell_count)
+                .map(|cell| {
+                    if !is_ocean(cell) {
+                        return Vec2::ZERO;
+                    }
+                    let ocean_neighbors: Vec<usize> = globe.topology.neighbors(cell).iter().copied().filter(|&n| is_ocean(n)).collect();
+                    let neighbors_sum: Vec2 = ocean_neighbors.iter().map(|&neighbor| currents[neighbor]).sum();
+                    (currents[cell] + neighbors_sum) / (1 + ocean_neighbors.len()) as f32
+                })
+                .collect();
+        }
+
+        // Make sure no current runs into the shore after the last blending.
+        for cell in 0..cell_count {
+            if let Some(normal) = coasts[cell] {
+                let into_coast = currents[cell].dot(normal).max(0.0);
+                currents[cell] -= normal * into_coast;
+            }
+        }
+
+        Self {
+            currents: currents.into_iter().map(Current::from_vec2).collect(),
+            time_in_seconds: wind.time_in_seconds,
+        }
+    }
+
+    pub fn cell(&self, cell: usize) -> Current {
+        self.currents[cell]
+    }
+
+    /// The current at a position, blended from the cells around it.
+    pub fn at(&self, globe: &Globe, position: &Position) -> Current {
+        let location = globe.locate(position);
+        Current::new(
+            location.interpolate(|cell| self.currents[cell].east),
+            location.interpolate(|cell| self.currents[cell].north),
+        )
+    }
+
+    /// Where something adrift at a position is carried by the current over a span of seconds.
+    pub fn drift(&self, globe: &Globe, position: &Position, seconds: u64) -> Position {
+        let current = self.at(globe, position);
+        let distance = (current.speed() * seconds as f32) as Meters;
+        position.destination(current.direction() as f64, distance, globe.radius).normalized()
+    }
+}
+
+/// The velocity over ground of a vessel moving through the water with a velocity, in the east and north m/s,
+/// while the water itself is carried along by a current.
+pub fn velocity_over_ground(through_water: Vec2, current: Current) -> Vec2 {
+    through_water + current.as_vec2()
+}
+
+/// Unit vector in the east and north plane of an ocean cell pointing towards the land around it, if it lies on a coast.
+fn coast_normal(globe: &Globe, cell: usize) -> Option<Vec2> {
+    if globe.cells[cell].surface != Surface::Ocean {
+        return None;
+    }
+
+    let (east, north) = globe.position_of(cell).local_axes();
+    let center = globe.topology.centroid(cell);
+    let towards_land: Vec3A = globe
+        .topology
+        .neighbors(cell)
+        .iter()
+        .filter(|&&neighbor| globe.cells[neighbor].surface != Surface::Ocean)
+        .map(|&neighbor| (globe.topology.centroid(neighbor) - center).normalize())
+        .sum();
+
+    let normal = Vec2::new(towards_land.dot(east), towards_land.dot(north));
+    (normal.length_squared() > 0.0).then(|| normal.normalize())
+}
+
+/// Turns a current running into a coast aside to run along it, `BOUNDARY_SPEEDUP` times faster than it came in.
+/// Water heading straight at the coast turns right in the northern hemisphere and left in the southern.
+fn deflect(current: Vec2, normal: Vec2, latitude: f64) -> Vec2 {
+    let into_coast = current.dot(normal);
+    if into_coast <= 0.0 {
+        return current;
+    }
+
+    let speed = current.length();
+    let along = current - normal * into_coast;
+    // Turning right from heading into the coast, in the northern hemisphere
+    let turn = if latitude >= 0.0 { -normal.perp() } else { normal.perp() };
+    let along_direction = if along.length() > 0.1 * speed { along.normalize() } else { turn };
+
+    along_direction * speed * BOUNDARY_SPEEDUP
+}
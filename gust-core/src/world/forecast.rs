@@ -0,0 +1,99 @@
+use rand::Rng;
+
+use super::{
+    globe::Globe,
+    position::Position,
+    weather::{Weather, WeatherKind},
+    wind::{Wind, WindField},
+};
+
+/// Seconds between the moments a forecast tells of.
+const INTERVAL: u64 = 3 * 60 * 60;
+/// How far ahead in seconds even the least skilled listener can tell the weather.
+const MIN_HORIZON: u64 = 6 * 60 * 60;
+/// Seconds the horizon of a forecast reaches further for each point of wind listening skill.
+const HORIZON_PER_SKILL: u64 = 7 * 60 * 60;
+/// Largest error in radians in the direction of the wind, for the most uncertain forecasts.
+const MAX_DIRECTION_ERROR: f32 = std::f32::consts::FRAC_PI_2;
+
+/// The weather a listener expects at some moment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ForecastEntry {
+    /// Game time in seconds this entry tells of.
+    pub time_in_seconds: u64,
+    pub wind: Wind,
+    /// Rain in mm per hour.
+    pub precipitation: f32,
+    /// How dangerous the weather is expected to be, from zero in fair weather to one in a hurricane.
+    pub danger: f32,
+    /// The weather system the listener expects to be under, if they sensed one.
+    pub system: Option<WeatherKind>,
+    /// How sure the listener is of this entry, from zero for a guess to one for a certainty.
+    pub confidence: f32,
+}
+
+/// What a listener reads from the wind about the weather to come at some place.
+#[derive(Clone, Debug)]
+pub struct Forecast {
+    /// Game time in seconds the forecast was made.
+    pub time_in_seconds: u64,
+    pub position: Position,
+    /// Entries at regular intervals from the time of the forecast up to its horizon.
+    pub entries: Vec<ForecastEntry>,
+}
+
+impl Forecast {
+    /// Foretells the weather at a position by reading the wind. The further ahead and the lower the
+    /// wind listening skill, the less accurate the forecast. Skilled listeners also see further ahead.
+    /// Storms that haven't formed yet can't be foreseen. The errors of the forecast are drawn from `rng`.
+    pub fn listen(globe: &Globe, wind: &WindField, weather: &Weather, position: &Position, time_in_seconds: u64, skill: f32, rng: &mut impl Rng) -> Self {
+        let horizon = MIN_HORIZON + (HORIZON_PER_SKILL as f32 * skill) as u64;
+
+        let mut future = weather.clone();
+        let mut entries = Vec::new();
+        let mut lead = 0;
+        while lead <= horizon {
+            let actual = future.conditions_at(globe, wind, position);
+
+            // Uncertainty grows with how far ahead the forecast looks and shrinks with skill.
+            let days_ahead = lead as f32 / (24.0 * 60.0 * 60.0);
+            let uncertainty = (0.25 + days_ahead) / (1.0 + skill);
+            let sensed = rng.gen::<f32>() >= uncertainty;
+            let mut error = || rng.gen_range(-1.0..=1.0) * uncertainty;
+
+            let direction = actual.wind.direction() + error() * MAX_DIRECTION_ERROR;
+            let speed = actual.wind.speed() * (1.0 + 0.6 * error()).max(0.0);
+
+            entries.push(ForecastEntry {
+                time_in_seconds: time_in_seconds + lead,
+                wind: Wind::from_direction(direction, speed),
+                precipitation: actual.precipitation * (1.0 + error()).max(0.0),
+                danger: (actual.danger + 0.3 * error()).clamp(0.0, 1.0),
+                system: actual.system.filter(|_| sensed),
+                confidence: (1.0 - uncertainty).clamp(0.0, 1.0),
+            });
+
+            future = future.predict(globe, wind, INTERVAL);
+            lead += INTERVAL;
+        }
+
+        Self {
+            time_in_seconds,
+            position: *position,
+            entries,
+        }
+    }
+
+    /// Game time in seconds of the last moment the forecast tells of.
+    pub fn horizon(&self) -> u64 {
+        self.entries.last().map_or(self.time_in_seconds, |entry| entry.time_in_seconds)
+    }
+
+    /// The entry closest to a moment within the span of the forecast.
+    pub fn at(&self, time_in_seconds: u64) -> Option<&ForecastEntry> {
+        if time_in_seconds < self.time_in_seconds || time_in_seconds > self.horizon() {
+            return None;
+        }
+        self.entries.iter().min_by_key(|entry| entry.time_in_seconds.abs_diff(time_in_seconds))
+    }
+}
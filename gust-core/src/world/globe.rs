@@ -1,17 +1,243 @@
+use glam::Vec2;
 use hexasphere::shapes::IcoSphere;
 
+use crate::util::noise::Perlin;
+
+use super::{
+    astronomy::Astronomy,
+    biome::Biome,
+    hydrology::HydrologyParams,
+    location::LocationIndex,
+    position::{Meters, EARTH_RADIUS},
+    tectonics::{Plate, TectonicParams},
+    topology::Topology,
+};
+
+/// What covers the surface of a cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surface {
+    Land,
+    Ocean,
+    Lake,
+}
+
+#[derive(Clone, Debug)]
 pub struct Cell {
-    elevation: f32,
+    /// Elevation in meters relative to sea level. Negative values are below the sea.
+    pub elevation: f32,
+    pub surface: Surface,
+    /// Index into `Globe::plates` of the tectonic plate this cell belongs to, if plates were simulated.
+    pub plate: Option<usize>,
+    /// Number of cells, including this one, whose water drains through this cell.
+    pub flow: f32,
+    /// The neighboring cell this cell's water drains into. `None` for the sea.
+    pub downstream: Option<usize>,
+    pub river: bool,
+    /// Mean temperature over the year, in °C.
+    pub mean_temperature: f32,
+    /// Total precipitation over the year, in millimeters.
+    pub annual_precipitation: f32,
+    /// Mean sunshine over the year at the latitude of this cell, in W/m².
+    pub mean_insolation: f32,
+    /// Temperature at the time of year of `Globe::climate_time_in_seconds`, in °C.
+    pub temperature: f32,
+    /// Rate of precipitation at the time of year of `Globe::climate_time_in_seconds`, in millimeters per year.
+    pub precipitation: f32,
+    /// Whether the sea over this cell is frozen over.
+    pub sea_ice: bool,
+    pub biome: Biome,
+}
+
+impl Cell {
+    /// Constructs an ocean cell at the given elevation, before any other property has been worked out.
+    pub fn new(elevation: f32) -> Self {
+        Self {
+            elevation,
+            surface: Surface::Ocean,
+            plate: None,
+            flow: 0.0,
+            downstream: None,
+            river: false,
+            mean_temperature: 0.0,
+            annual_precipitation: 0.0,
+            mean_insolation: 0.0,
+            temperature: 0.0,
+            precipitation: 0.0,
+            sea_ice: false,
+            biome: Biome::Ocean,
+        }
+    }
+
+    pub fn is_land(&self) -> bool {
+        self.surface == Surface::Land
+    }
+}
+
+/// Parameters controlling procedural generation of a globe.
+pub struct GenerationParams {
+    pub radius: Meters,
+    /// Number of subdivisions of the icosphere. The globe has 10 * (subdivisions + 1)² + 2 cells.
+    pub subdivisions: usize,
+    /// Frequency of the first noise octave, sampled on the unit sphere.
+    pub frequency: f32,
+    pub octaves: u32,
+    /// Frequency multiplier between successive octaves.
+    pub lacunarity: f32,
+    /// Amplitude multiplier between successive octaves.
+    pub persistence: f32,
+    /// Noise value at which the land meets the sea. Raising it floods more of the globe.
+    pub sea_level: f32,
+    /// Meters of elevation per unit of noise above or below the sea level.
+    pub elevation_scale: f32,
+    /// Shapes continents with simulated plate tectonics on top of the noise. Skipped when `None`.
+    pub tectonics: Option<TectonicParams>,
+    /// Erodes the terrain and traces rivers and lakes. Skipped when `None`.
+    pub hydrology: Option<HydrologyParams>,
+    /// Axial tilt and lengths of the year and day, which set the seasons.
+    pub astronomy: Astronomy,
+}
+
+impl Default for GenerationParams {
+    fn default() -> Self {
+        Self {
+            radius: EARTH_RADIUS,
+            subdivisions: 8,
+            frequency: 1.6,
+            octaves: 6,
+            lacunarity: 2.0,
+            persistence: 0.5,
+            sea_level: 0.05,
+            elevation_scale: 12_000.0,
+            tectonics: Some(TectonicParams::default()),
+            hydrology: Some(HydrologyParams::default()),
+            astronomy: Astronomy::default(),
+        }
+    }
 }
 
 pub struct Globe {
-    pub data: IcoSphere<Cell>,
+    /// The shape of the globe. Each point of the icosphere is the center of a cell.
+    pub data: IcoSphere<()>,
+    /// Radius of the globe at sea level.
+    pub radius: Meters,
+    /// Cells of the globe, indexed in the same order as the points of `data`.
+    pub cells: Vec<Cell>,
+    pub plates: Vec<Plate>,
+    pub topology: Topology,
+    pub astronomy: Astronomy,
+    /// Game time in seconds the temperature and precipitation of the cells were last worked out for.
+    pub climate_time_in_seconds: u64,
+    pub(crate) location_index: LocationIndex,
 }
 
 impl Globe {
+    /// Constructs a featureless globe, covered entirely by a sea with no depth.
     pub fn new() -> Self {
+        let data = IcoSphere::new(GenerationParams::default().subdivisions, |_| ());
+        let cells = vec![Cell::new(0.0); data.raw_points().len()];
+
+        let mut globe = Self::from_cells(data, EARTH_RADIUS, Astronomy::default(), cells);
+        globe.compute_mean_climate();
+        globe.classify_biomes();
+        globe
+    }
+
+    /// Procedurally generates a globe. The same seed and parameters always produce the same globe.
+    pub fn generate(seed: u64, params: &GenerationParams) -> Self {
+        let noise = Perlin::new(seed);
+        let data = IcoSphere::new(params.subdivisions, |_| ());
+
+        let cells = data
+            .raw_points()
+            .iter()
+            .map(|point| {
+                // Sampling the noise in 3D on the sphere itself avoids any seams or distortion at the poles.
+                let p = *point * params.frequency;
+                let height = noise.fractal(p.x, p.y, p.z, params.octaves, params.lacunarity, params.persistence);
+
+                Cell::new((height - params.sea_level) * params.elevation_scale)
+            })
+            .collect();
+
+        let mut globe = Self::from_cells(data, params.radius, params.astronomy, cells);
+
+        if let Some(tectonics) = &params.tectonics {
+            globe.simulate_tectonics(seed, tectonics);
+        }
+
+        globe.classify_surface();
+
+        if let Some(hydrology) = &params.hydrology {
+            globe.simulate_hydrology(hydrology);
+        }
+
+        globe.compute_mean_climate();
+        globe.classify_biomes();
+
+        globe
+    }
+
+    fn from_cells(data: IcoSphere<()>, radius: Meters, astronomy: Astronomy, cells: Vec<Cell>) -> Self {
+        let topology = Topology::new(&data);
+        let location_index = LocationIndex::new(data.raw_points());
+
         Self {
-            data: IcoSphere::new(8, |_| Cell { elevation: 0.0 }),
+            data,
+            radius,
+            cells,
+            plates: Vec::new(),
+            topology,
+            astronomy,
+            climate_time_in_seconds: 0,
+            location_index,
+        }
+    }
+
+    /// Marks every cell above sea level as land and every other cell as ocean. Lakes are lost.
+    pub fn classify_surface(&mut self) {
+        for cell in &mut self.cells {
+            cell.surface = if cell.elevation > 0.0 { Surface::Land } else { Surface::Ocean };
         }
     }
+
+    pub fn cell_count(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn land_fraction(&self) -> f32 {
+        self.cells.iter().filter(|cell| cell.is_land()).count() as f32 / self.cells.len() as f32
+    }
+
+    /// Slope of the terrain at a cell, as the east and north rise in meters per meter of ground,
+    /// fitted to the elevations of its neighbors. Water cells are taken to be flat at sea level.
+    pub fn elevation_gradient(&self, cell: usize) -> Vec2 {
+        let height = |cell: usize| self.cells[cell].elevation.max(0.0);
+        let (east, north) = self.position_of(cell).local_axes();
+        let center = self.topology.centroid(cell);
+
+        // Least squares fit of a plane through the differences to each neighbor
+        let (mut ee, mut en, mut nn, mut eh, mut nh) = (0.0, 0.0, 0.0, 0.0, 0.0);
+        for &neighbor in self.topology.neighbors(cell) {
+            let offset = (self.topology.centroid(neighbor) - center) * self.radius as f32;
+            let (x, y) = (offset.dot(east), offset.dot(north));
+            let rise = height(neighbor) - height(cell);
+            ee += x * x;
+            en += x * y;
+            nn += y * y;
+            eh += x * rise;
+            nh += y * rise;
+        }
+
+        let determinant = ee * nn - en * en;
+        if determinant.abs() < f32::EPSILON {
+            return Vec2::ZERO;
+        }
+        Vec2::new(nn * eh - en * nh, ee * nh - en * eh) / determinant
+    }
+}
+
+impl Default for Globe {
+    fn default() -> Self {
+        Self::new()
+    }
 }
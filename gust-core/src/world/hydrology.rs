@@ -0,0 +1,204 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use super::globe::{Globe, Surface};
+
+/// Parameters of the erosion and river generation world generation pass.
+#[derive(Clone, Debug)]
+pub struct HydrologyParams {
+    /// How many times the terrain is eroded by its own drainage before the final rivers are traced.
+    pub erosion_iterations: usize,
+    /// Meters of elevation removed per unit of stream power in each erosion iteration.
+    pub erosion_rate: f32,
+    /// Flow accumulation, in cells of catchment, above which a land cell carries a river.
+    pub river_threshold: f32,
+    /// Depressions deeper than this many meters below their spill point hold a lake.
+    pub lake_min_depth: f32,
+}
+
+impl Default for HydrologyParams {
+    fn default() -> Self {
+        Self {
+            erosion_iterations: 4,
+            erosion_rate: 2.5,
+            river_threshold: 3.0,
+            lake_min_depth: 25.0,
+        }
+    }
+}
+
+/// A cell waiting to be flooded, ordered so that the lowest one is popped first from a `BinaryHeap`.
+struct Pending {
+    level: f32,
+    cell: usize,
+}
+
+impl PartialEq for Pending {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Pending {}
+
+impl PartialOrd for Pending {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Pending {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.level.total_cmp(&self.level).then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+/// Result of flooding the globe from the sea upwards.
+struct Drainage {
+    /// Height the water would fill each cell up to, at least the cell's elevation.
+    water_levels: Vec<f32>,
+    /// The cell each land cell drains into.
+    downstream: Vec<Option<usize>>,
+    /// Land cells, in the order they were flooded. Every cell comes after the cell it drains into.
+    order: Vec<usize>,
+}
+
+impl Globe {
+    /// Erodes the land along its drainage, fills depressions into lakes and traces rivers.
+    /// Sets the flow, downstream cell and river flag of every cell, and marks lake cells.
+    /// Expects land and ocean to have been classified.
+    pub fn simulate_hydrology(&mut self, params: &HydrologyParams) {
+        for _ in 0..params.erosion_iterations {
+            let drainage = self.drain();
+            let flows = self.accumulate_flow(&drainage);
+            self.erode(&drainage, &flows, params.erosion_rate);
+        }
+
+        let drainage = self.drain();
+        let flows = self.accumulate_flow(&drainage);
+
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            cell.flow = flows[index];
+            cell.downstream = drainage.downstream[index];
+
+            if cell.surface == Surface::Ocean {
+                cell.river = false;
+                continue;
+            }
+
+            if drainage.water_levels[index] - cell.elevation >= params.lake_min_depth {
+                cell.surface = Surface::Lake;
+                cell.river = false;
+            } else {
+                cell.surface = Surface::Land;
+                cell.river = flows[index] >= params.river_threshold;
+            }
+        }
+    }
+
+    /// The cells a drop of water passes through on its way from the given cell to the sea,
+    /// starting with the given cell itself.
+    pub fn downstream_path(&self, cell: usize) -> Vec<usize> {
+        let mut path = vec![cell];
+        let mut current = cell;
+
+        while let Some(next) = self.cells[current].downstream {
+            path.push(next);
+            current = next;
+        }
+
+        path
+    }
+
+    /// Indices of all cells carrying a river.
+    pub fn river_cells(&self) -> impl Iterator<Item = usize> + '_ {
+        self.cells.iter().enumerate().filter(|(_, cell)| cell.river).map(|(index, _)| index)
+    }
+
+    /// Floods the land from the sea upwards, a "priority flood". Each land cell drains into the
+    /// neighbor it was flooded from, so every land cell has a path to the sea that never climbs
+    /// above the water level of a depression's spill point.
+    fn drain(&self) -> Drainage {
+        let cell_count = self.cells.len();
+        let mut water_levels: Vec<f32> = self.cells.iter().map(|cell| cell.elevation).collect();
+        let mut downstream = vec![None; cell_count];
+        let mut flooded = vec![false; cell_count];
+        let mut order = Vec::with_capacity(cell_count);
+        let mut queue = BinaryHeap::new();
+
+        for (index, cell) in self.cells.iter().enumerate() {
+            if cell.surface == Surface::Ocean {
+                flooded[index] = true;
+                queue.push(Pending {
+                    level: cell.elevation,
+                    cell: index,
+                });
+            }
+        }
+
+        // A globe without any sea drains into its lowest point
+        if queue.is_empty() {
+            let lowest = (0..cell_count)
+                .min_by(|a, b| self.cells[*a].elevation.total_cmp(&self.cells[*b].elevation))
+                .expect("Globe has no cells");
+            flooded[lowest] = true;
+            queue.push(Pending {
+                level: water_levels[lowest],
+                cell: lowest,
+            });
+        }
+
+        while let Some(Pending { level, cell }) = queue.pop() {
+            for &neighbor in self.topology.neighbors(cell) {
+                if flooded[neighbor] {
+                    continue;
+                }
+                flooded[neighbor] = true;
+
+                water_levels[neighbor] = water_levels[neighbor].max(level);
+                downstream[neighbor] = Some(cell);
+                order.push(neighbor);
+                queue.push(Pending {
+                    level: water_levels[neighbor],
+                    cell: neighbor,
+                });
+            }
+        }
+
+        Drainage { water_levels, downstream, order }
+    }
+
+    /// Counts how many cells drain through each land cell, including the cell itself.
+    fn accumulate_flow(&self, drainage: &Drainage) -> Vec<f32> {
+        let mut flows = vec![0.0; self.cells.len()];
+
+        for &cell in &drainage.order {
+            flows[cell] = 1.0;
+        }
+
+        // Visiting upstream cells first carries each cell's flow all the way down to the sea.
+        for &cell in drainage.order.iter().rev() {
+            if let Some(next) = drainage.downstream[cell] {
+                if self.cells[next].surface != Surface::Ocean {
+                    flows[next] += flows[cell];
+                }
+            }
+        }
+
+        flows
+    }
+
+    /// Cuts down land cells in proportion to the stream power of the water flowing out of them,
+    /// never below the cell they drain into, so that rivers carve valleys without digging pits.
+    fn erode(&mut self, drainage: &Drainage, flows: &[f32], rate: f32) {
+        for &cell in &drainage.order {
+            let Some(next) = drainage.downstream[cell] else { continue };
+
+            let elevation = self.cells[cell].elevation;
+            let floor = self.cells[next].elevation.max(0.0) + 1.0;
+            let slope = (elevation - drainage.water_levels[next]).max(0.0);
+            let eroded = elevation - rate * flows[cell].sqrt() * slope.sqrt();
+
+            self.cells[cell].elevation = eroded.max(floor).min(elevation);
+        }
+    }
+}
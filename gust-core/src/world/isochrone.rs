@@ -0,0 +1,203 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    f32::consts::TAU,
+};
+
+use glam::Vec2;
+
+use crate::ships::polar::SpeedPolar;
+
+use super::{
+    currents::{self, CurrentField},
+    globe::{Globe, Surface},
+    pathfinding::{Route, TravelMode},
+    position::{Meters, Position},
+    wind::{self, Wind},
+};
+
+/// Seconds between successive isochrones, the fronts of the positions reachable in the same time.
+const TIME_STEP: u64 = 6 * 60 * 60;
+/// Number of headings, evenly spaced around the compass, tried from each position on an isochrone.
+const HEADING_COUNT: usize = 36;
+/// Size in radians of the squares of latitude and longitude that thin out the isochrones. Only the first position
+/// to reach a square goes on from it, so each stretch of sea is sailed from once, and the isochrones flow around
+/// the land rather than piling up against it.
+const SQUARE_SIZE: f64 = std::f64::consts::PI / 180.0;
+/// Most isochrones a ship sails on the same heading to leave the square it is in, when it is too slow
+/// to leave it before the next one.
+const CROSSING_STEPS: u64 = 12;
+/// Speed in m/s of the light, shifting airs a ship can still find in the belts of calm, where the prevailing
+/// wind dies away on average but seldom for long.
+const LIGHT_AIRS: f32 = 2.0;
+/// Longest voyage searched for, in seconds.
+const MAX_VOYAGE: u64 = 120 * 24 * 60 * 60;
+/// Slowest speed over ground in m/s worth following, below which a heading is as good as becalmed.
+const MIN_SPEED: f32 = 0.05;
+
+/// A square of latitude and longitude, by how many squares it lies north of the equator and east of the prime meridian.
+type Square = (i32, i32);
+
+/// A position reached on an isochrone, and how it was reached.
+struct Node {
+    position: Position,
+    /// Index of the node this one was sailed from.
+    parent: Option<usize>,
+    /// Index of the heading sailed from the parent.
+    heading: usize,
+}
+
+impl Globe {
+    /// Finds the fastest way under sail between two ports, or any positions at sea, with the isochrone method.
+    ///
+    /// From the start, the ship sails every heading for a while, carried by the current and driven by the
+    /// prevailing wind of the moment through its speed polar. The positions reached make up an isochrone, and the
+    /// search goes on from each of them in turn until the destination comes within reach. Land and sea ice are
+    /// sailed around. `None` if either end lies inland, or the destination can't be reached within a few months.
+    pub fn find_sea_route(&self, from: &Position, to: &Position, time_in_seconds: u64, currents: &CurrentField, polar: &SpeedPolar) -> Option<Route> {
+        let ports = [self.cell_at(from), self.cell_at(to)];
+        if !ports.iter().all(|&cell| self.is_port(cell)) {
+            return None;
+        }
+        let is_clear = |start: &Position, end: &Position| self.is_clear(&ports, start, end);
+
+        let mut nodes: Vec<Node> = Vec::new();
+        let mut reached = HashSet::new();
+        // Positions waiting to be sailed from by the isochrone they reach, keeping the one closest to the
+        // destination in each square
+        let mut isochrones: BTreeMap<u64, BTreeMap<Square, (Meters, Node)>> = BTreeMap::new();
+        let start = Node {
+            position: *from,
+            parent: None,
+            heading: 0,
+        };
+        isochrones.entry(0).or_default().insert(square(from), (0.0, start));
+        // The earliest arrival found so far, in seconds from the start, and the node it was made from
+        let mut arrival: Option<(u64, usize)> = None;
+
+        while let Some((step, isochrone)) = isochrones.pop_first() {
+            let time = time_in_seconds + step * TIME_STEP;
+            if step * TIME_STEP > MAX_VOYAGE || arrival.is_some_and(|(duration, _)| step * TIME_STEP >= duration) {
+                break;
+            }
+
+            for (key, (_, node)) in isochrone {
+                if !reached.insert(key) {
+                    continue;
+                }
+                let index = nodes.len();
+                let position = node.position;
+                nodes.push(node);
+
+                let wind = wind::prevailing_wind_at(self, &position, time);
+                let wind = Wind::from_direction(wind.direction(), wind.speed().max(LIGHT_AIRS));
+                let current = currents.at(self, &position);
+                let velocity = |heading: f32| currents::velocity_over_ground(polar.velocity(heading, wind), current);
+                let velocities: Vec<Vec2> = (0..HEADING_COUNT).map(|heading| velocity(heading as f32 * TAU / HEADING_COUNT as f32)).collect();
+
+                // Making for the destination if it lies within reach, on whichever heading closes on it fastest,
+                // tacking back and forth when it lies upwind.
+                let bearing = position.initial_bearing_to(to) as f32;
+                let towards = Vec2::new(bearing.sin(), bearing.cos());
+                let made_good = velocities.iter().map(|velocity| velocity.dot(towards)).fold(velocity(bearing).dot(towards), f32::max);
+                let remaining = position.distance_to(to, self.radius);
+                if made_good > MIN_SPEED && remaining <= made_good as f64 * (CROSSING_STEPS * TIME_STEP) as f64 && is_clear(&position, to) {
+                    let duration = step * TIME_STEP + (remaining / made_good as f64) as u64;
+                    if arrival.is_none_or(|(best, _)| duration < best) {
+                        arrival = Some((duration, index));
+                    }
+                }
+
+                for (heading, over_ground) in velocities.into_iter().enumerate() {
+                    if over_ground.length() < MIN_SPEED {
+                        continue;
+                    }
+                    let course = over_ground.x.atan2(over_ground.y) as f64;
+                    let step_length = over_ground.length() as f64 * TIME_STEP as f64;
+
+                    // Sail on until out of this square, as the positions within it are sailed from already.
+                    let Some((steps, next)) = (1..=CROSSING_STEPS)
+                        .map(|steps| (steps, position.destination(course, step_length * steps as f64, self.radius).normalized()))
+                        .find(|(_, next)| square(next) != key)
+                    else {
+                        continue;
+                    };
+                    let next_key = square(&next);
+                    if reached.contains(&next_key) {
+                        continue;
+                    }
+
+                    let remaining = next.distance_to(to, self.radius);
+                    let candidates = isochrones.entry(step + steps).or_default();
+                    if candidates.get(&next_key).is_none_or(|(closest, _)| remaining < *closest) && is_clear(&position, &next) {
+                        let node = Node {
+                            position: next,
+                            parent: Some(index),
+                            heading,
+                        };
+                        candidates.insert(next_key, (remaining, node));
+                    }
+                }
+            }
+        }
+
+        arrival.map(|(duration, last)| self.sea_route(&nodes, last, to, duration, &ports))
+    }
+
+    /// Whether a ship can set off from or arrive at a cell: any cell of open sea, or land on its coast.
+    fn is_port(&self, cell: usize) -> bool {
+        match self.cells[cell].surface {
+            Surface::Ocean => true,
+            Surface::Land => self.topology.neighbors(cell).iter().any(|&n| self.cells[n].surface == Surface::Ocean),
+            Surface::Lake => false,
+        }
+    }
+
+    /// Whether a ship can sail straight from one position to another over open sea, or the cells of its ports.
+    fn is_clear(&self, ports: &[usize; 2], start: &Position, end: &Position) -> bool {
+        self.cells_along(start, end)
+            .into_iter()
+            .all(|cell| ports.contains(&cell) || self.cells[cell].surface == Surface::Ocean && !self.cells[cell].sea_ice)
+    }
+
+    /// Traces the way back from the last node reached before the destination. Runs of steps sailed on the same
+    /// heading become a single leg, unless the great circle between their ends would cut across land.
+    fn sea_route(&self, nodes: &[Node], last: usize, destination: &Position, duration: u64, ports: &[usize; 2]) -> Route {
+        let mut path = vec![last];
+        while let Some(parent) = nodes[*path.last().unwrap()].parent {
+            path.push(parent);
+        }
+        path.reverse();
+
+        let mut waypoints = vec![nodes[path[0]].position];
+        for pair in path.windows(2).skip(1) {
+            // Keep the position where the heading changes, as the ship tacks, jibes or bears away.
+            let (turn, next) = (&nodes[pair[0]].position, &nodes[pair[1]].position);
+            if nodes[pair[0]].heading != nodes[pair[1]].heading || !self.is_clear(ports, waypoints.last().unwrap(), next) {
+                waypoints.push(*turn);
+            }
+        }
+        if path.len() > 1 {
+            waypoints.push(nodes[last].position);
+        }
+        waypoints.push(*destination);
+
+        let mut cells: Vec<usize> = path.iter().map(|&index| self.cell_at(&nodes[index].position)).collect();
+        cells.push(self.cell_at(destination));
+        cells.dedup();
+
+        let distance = waypoints.windows(2).map(|leg| leg[0].distance_to(&leg[1], self.radius)).sum();
+
+        Route {
+            waypoints,
+            cells,
+            distance,
+            duration,
+            mode: TravelMode::Sailing,
+        }
+    }
+}
+
+/// The square of latitude and longitude a position lies in.
+fn square(position: &Position) -> Square {
+    ((position.latitude() / SQUARE_SIZE).floor() as i32, (position.longitude() / SQUARE_SIZE).floor() as i32)
+}
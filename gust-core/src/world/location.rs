@@ -0,0 +1,182 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use glam::Vec3A;
+
+use super::{globe::Globe, position::Position, topology::Topology};
+
+const GRID_ROWS: usize = 36;
+const GRID_COLUMNS: usize = 72;
+
+/// Where a point lies on the globe.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CellLocation {
+    /// The cell containing the point, the one whose center is nearest to it.
+    pub cell: usize,
+    /// The three cells whose centers form the triangle of the icosphere containing the point.
+    pub triangle: [usize; 3],
+    /// Barycentric weights of the point within `triangle`. They are non-negative and add up to 1.
+    pub weights: [f32; 3],
+}
+
+impl CellLocation {
+    /// Blends a per-cell value at the point from the values of the corners of the enclosing triangle.
+    pub fn interpolate(&self, value: impl Fn(usize) -> f32) -> f32 {
+        self.triangle.iter().zip(self.weights).map(|(&cell, weight)| value(cell) * weight).sum()
+    }
+}
+
+/// Spatial hash over latitude and longitude, pointing each bucket at the cell nearest its center.
+/// Lookups start from there and walk the mesh the rest of the way.
+pub struct LocationIndex {
+    start_cells: Vec<usize>,
+}
+
+impl LocationIndex {
+    pub fn new(points: &[Vec3A]) -> Self {
+        let start_cells = (0..GRID_ROWS * GRID_COLUMNS)
+            .map(|bucket| {
+                let latitude = ((bucket / GRID_COLUMNS) as f32 + 0.5) / GRID_ROWS as f32 * PI - FRAC_PI_2;
+                let longitude = ((bucket % GRID_COLUMNS) as f32 + 0.5) / GRID_COLUMNS as f32 * 2.0 * PI - PI;
+                let center = Position::new(latitude as f64, longitude as f64).to_unit_vector();
+
+                (0..points.len())
+                    .max_by(|a, b| points[*a].dot(center).total_cmp(&points[*b].dot(center)))
+                    .expect("Globe has no cells")
+            })
+            .collect();
+
+        Self { start_cells }
+    }
+
+    fn start_cell(&self, position: &Position) -> usize {
+        let latitude = position.latitude() as f32;
+        let longitude = position.longitude() as f32;
+
+        let row = ((latitude + FRAC_PI_2) / PI * GRID_ROWS as f32) as usize;
+        let column = ((longitude + PI).rem_euclid(2.0 * PI) / (2.0 * PI) * GRID_COLUMNS as f32) as usize;
+
+        self.start_cells[row.min(GRID_ROWS - 1) * GRID_COLUMNS + column.min(GRID_COLUMNS - 1)]
+    }
+}
+
+impl Globe {
+    /// Finds the cell a position lies in, along with its place within the triangle of cell centers around it.
+    pub fn locate(&self, position: &Position) -> CellLocation {
+        let point = position.to_unit_vector();
+        let points = self.data.raw_points();
+
+        // The icosphere is a Delaunay triangulation, so always stepping to the neighbor nearest to the
+        // point can't get stuck before reaching the cell center nearest to it.
+        let mut cell = self.location_index.start_cell(position);
+        loop {
+            let closer = self
+                .topology
+                .neighbors(cell)
+                .iter()
+                .copied()
+                .max_by(|a, b| points[*a].dot(point).total_cmp(&points[*b].dot(point)))
+                .filter(|&neighbor| points[neighbor].dot(point) > points[cell].dot(point));
+
+            match closer {
+                Some(neighbor) => cell = neighbor,
+                None => break,
+            }
+        }
+
+        let (triangle, weights) = enclosing_triangle(&self.topology, points, cell, point);
+
+        CellLocation { cell, triangle, weights }
+    }
+
+    /// The cell a position lies in.
+    pub fn cell_at(&self, position: &Position) -> usize {
+        self.locate(position).cell
+    }
+
+    /// The cells the great circle from one position to another passes through, in order from the cell of `from`
+    /// to the cell of `to`, however little of a cell it crosses.
+    pub fn cells_along(&self, from: &Position, to: &Position) -> Vec<usize> {
+        let points = self.data.raw_points();
+        let start = from.to_unit_vector();
+        let end = to.to_unit_vector();
+        let last = self.cell_at(to);
+        let length = start.angle_between(end);
+        // Unit vector at the start, square to it, that the great circle turns towards
+        let towards = (end - start * start.dot(end)).normalize_or_zero();
+
+        let mut cell = self.cell_at(from);
+        let mut cells = vec![cell];
+        let mut angle = 0.0;
+        while cell != last && towards != Vec3A::ZERO && cells.len() <= self.cells.len() {
+            // Along the circle, the center of this cell is closer than that of a neighbor by a multiple of the cosine
+            // of the angle gone less a phase. The circle leaves for the neighbor where that cosine turns negative.
+            let exit = self
+                .topology
+                .neighbors(cell)
+                .iter()
+                .map(|&neighbor| {
+                    let difference = points[cell] - points[neighbor];
+                    let phase = towards.dot(difference).atan2(start.dot(difference));
+                    ((phase + FRAC_PI_2).rem_euclid(2.0 * PI), neighbor)
+                })
+                .filter(|&(crossing, _)| crossing >= angle)
+                .min_by(|a, b| a.0.total_cmp(&b.0));
+
+            match exit {
+                Some((crossing, neighbor)) if crossing <= length => {
+                    angle = crossing;
+                    cell = neighbor;
+                    cells.push(cell);
+                }
+                _ => break,
+            }
+        }
+        if cell != last {
+            cells.push(last);
+        }
+        cells
+    }
+
+    /// Position of the center of a cell.
+    pub fn position_of(&self, cell: usize) -> Position {
+        Position::from_vector(self.data.raw_points()[cell])
+    }
+}
+
+/// Finds the triangle around a cell that contains the point, falling back on the triangles around the
+/// cell's neighbors and then on the triangle the point is least outside of.
+fn enclosing_triangle(topology: &Topology, points: &[Vec3A], cell: usize, point: Vec3A) -> ([usize; 3], [f32; 3]) {
+    let candidates = std::iter::once(cell)
+        .chain(topology.neighbors(cell).iter().copied())
+        .flat_map(|candidate| topology.cell_triangles(candidate).iter().copied());
+
+    let mut best = None;
+    let mut best_min_weight = f32::NEG_INFINITY;
+
+    for triangle in candidates {
+        let corners = topology.triangles()[triangle];
+        let weights = barycentric_weights(points, corners, point);
+        let min_weight = weights[0].min(weights[1]).min(weights[2]);
+
+        if min_weight >= 0.0 {
+            return (corners, weights);
+        }
+        if min_weight > best_min_weight {
+            best_min_weight = min_weight;
+            best = Some((corners, weights));
+        }
+    }
+
+    let (corners, weights) = best.expect("Cell has no triangles");
+    let clamped = weights.map(|weight| weight.max(0.0));
+    let sum: f32 = clamped.iter().sum();
+    (corners, clamped.map(|weight| weight / sum))
+}
+
+/// Barycentric weights of the point's projection from the center of the sphere onto the plane of the triangle.
+fn barycentric_weights(points: &[Vec3A], [a, b, c]: [usize; 3], point: Vec3A) -> [f32; 3] {
+    let (a, b, c) = (points[a], points[b], points[c]);
+    let weights = [point.dot(b.cross(c)), point.dot(c.cross(a)), point.dot(a.cross(b))];
+    let sum: f32 = weights.iter().sum();
+    weights.map(|weight| weight / sum)
+}
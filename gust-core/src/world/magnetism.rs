@@ -0,0 +1,112 @@
+use std::f64::consts::{PI, TAU};
+
+use glam::Vec2;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::position::Position;
+
+/// Strength in microtesla of the field of the dipole along the magnetic equator, about that of the Earth.
+const EQUATORIAL_STRENGTH: f32 = 30.0;
+/// Range of latitudes in degrees the magnetic north pole is placed in, off the pole the globe turns about.
+const POLE_LATITUDES: (f64, f64) = (75.0, 86.0);
+/// Number of anomalies generated, and the ranges of their strengths in microtesla and their radii in degrees.
+const ANOMALY_COUNT: usize = 8;
+const ANOMALY_STRENGTHS: (f32, f32) = (3.0, 12.0);
+const ANOMALY_RADII: (f64, f64) = (2.0, 8.0);
+
+/// A patch of rock, such as a mass of iron ore, that pulls compass needles towards it, or pushes them away.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Anomaly {
+    pub position: Position,
+    /// Strength in microtesla of the field at the center of the anomaly, negative where it pushes needles away.
+    pub strength: f32,
+    /// Angle in radians from the center over which the field of the anomaly fades.
+    pub radius: f64,
+}
+
+/// The magnetic field of the globe, that of a dipole tilted off the axis the globe turns about, with anomalies
+/// in places. Compass needles line up with it, so they point to the magnetic north pole rather than the true one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MagneticField {
+    /// Where the axis of the dipole comes out in the north, towards which compass needles point.
+    pub north_pole: Position,
+    /// Strength in microtesla of the field of the dipole along the magnetic equator.
+    pub equatorial_strength: f32,
+    pub anomalies: Vec<Anomaly>,
+}
+
+impl MagneticField {
+    /// The field of a dipole alone, without any anomalies.
+    pub fn dipole(north_pole: Position, equatorial_strength: f32) -> Self {
+        Self {
+            north_pole,
+            equatorial_strength,
+            anomalies: Vec::new(),
+        }
+    }
+
+    /// Generates a field with its magnetic north pole placed near the true one, and anomalies scattered around the globe.
+    pub fn generate(seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let latitude = rng.gen_range(POLE_LATITUDES.0..POLE_LATITUDES.1).to_radians();
+        let north_pole = Position::new(latitude, rng.gen_range(-PI..PI));
+
+        let anomalies = (0..ANOMALY_COUNT)
+            .map(|_| {
+                // Uniformly distributed over the sphere
+                let position = Position::new(rng.gen_range(-1.0_f64..1.0).asin(), rng.gen_range(-PI..PI));
+                let strength = rng.gen_range(ANOMALY_STRENGTHS.0..ANOMALY_STRENGTHS.1);
+                Anomaly {
+                    position,
+                    strength: if rng.gen() { strength } else { -strength },
+                    radius: rng.gen_range(ANOMALY_RADII.0..ANOMALY_RADII.1).to_radians(),
+                }
+            })
+            .collect();
+
+        Self {
+            anomalies,
+            ..Self::dipole(north_pole, EQUATORIAL_STRENGTH)
+        }
+    }
+
+    /// The part of the field along the surface at a position, in microtesla towards the east and the north.
+    /// The field of the dipole runs along the great circle to the magnetic north pole, strongest at the magnetic equator.
+    pub fn horizontal_field(&self, position: &Position) -> Vec2 {
+        let point = position.to_unit_vector();
+        let along_surface = |target: &Position| {
+            let direction = target.to_unit_vector();
+            direction - direction.dot(point) * point
+        };
+
+        let mut field = along_surface(&self.north_pole) * self.equatorial_strength;
+        for anomaly in &self.anomalies {
+            let distance = position.central_angle_to(&anomaly.position) / anomaly.radius;
+            let towards = along_surface(&anomaly.position).normalize_or_zero();
+            field += towards * anomaly.strength * (-distance * distance).exp() as f32;
+        }
+
+        let (east, north) = position.local_axes();
+        Vec2::new(field.dot(east), field.dot(north))
+    }
+
+    /// Magnetic declination in radians at a position: the angle from true north to where compass needles point,
+    /// positive to the east. Zero where the field vanishes, as at the magnetic poles.
+    pub fn declination(&self, position: &Position) -> f64 {
+        let field = self.horizontal_field(position);
+        if field.length_squared() <= f32::EPSILON {
+            return 0.0;
+        }
+        (field.x as f64).atan2(field.y as f64)
+    }
+
+    /// The true heading in radians, clockwise from north, of a heading steered by compass at a position.
+    pub fn true_heading(&self, compass_heading: f64, position: &Position) -> f64 {
+        (compass_heading + self.declination(position)).rem_euclid(TAU)
+    }
+
+    /// The heading in radians to steer by compass at a position to keep a true heading.
+    pub fn compass_heading(&self, true_heading: f64, position: &Position) -> f64 {
+        (true_heading - self.declination(position)).rem_euclid(TAU)
+    }
+}
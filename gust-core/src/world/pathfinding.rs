@@ -0,0 +1,176 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use super::{
+    globe::Globe,
+    position::{Meters, Position},
+};
+
+/// Meters of level walking that take as long as climbing one meter, after Naismith's rule
+/// of an hour for every 5 km walked and another for every 600 m climbed.
+const CLIMB_DISTANCE: f32 = 5000.0 / 600.0;
+/// Meters of level walking that take as long as fording a river carrying the water of a single cell.
+/// Bigger rivers take longer to find a ford over, growing with the square root of their flow.
+const FORD_DISTANCE: f32 = 3000.0;
+/// Largest factor a river's flow can raise the time taken to ford it by.
+const MAX_FORD_FACTOR: f32 = 5.0;
+/// Walking speed in m/s over open grassland of the least skilled traveler, and the extra speed
+/// for each point of traveling skill.
+const BASE_WALKING_SPEED: f32 = 1.0;
+const WALKING_SPEED_PER_SKILL: f32 = 0.05;
+
+/// How a route is traveled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TravelMode {
+    Walking,
+    Sailing,
+}
+
+/// A way from one position to another through a series of waypoints, to be followed in order.
+#[derive(Clone, Debug)]
+pub struct Route {
+    /// Positions to pass through, starting at the start and ending at the destination.
+    pub waypoints: Vec<Position>,
+    /// Cells the route passes through, in order.
+    pub cells: Vec<usize>,
+    /// Length of the route over the ground, in meters.
+    pub distance: Meters,
+    /// Estimated time to follow the route, in seconds spent on the move.
+    pub duration: u64,
+    pub mode: TravelMode,
+}
+
+impl Route {
+    pub fn destination(&self) -> Option<&Position> {
+        self.waypoints.last()
+    }
+}
+
+/// A cell waiting to be explored, ordered so that the one with the lowest estimated total cost is popped first
+/// from a `BinaryHeap`.
+struct Candidate {
+    estimate: f32,
+    cell: usize,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.total_cmp(&self.estimate).then_with(|| other.cell.cmp(&self.cell))
+    }
+}
+
+/// Walking speed in m/s over open grassland of a traveler with the given traveling skill.
+pub fn walking_speed(traveling_skill: f32) -> f32 {
+    BASE_WALKING_SPEED + WALKING_SPEED_PER_SKILL * traveling_skill
+}
+
+impl Globe {
+    /// Finds the quickest way on foot between two positions on land with the A* search over the cells,
+    /// going around water and weighing the terrain, the climbs and the rivers to ford along the way.
+    /// The duration is estimated from the traveling skill. `None` if either position lies on water or
+    /// no way over land joins them.
+    pub fn find_land_route(&self, from: &Position, to: &Position, traveling_skill: f32) -> Option<Route> {
+        let start = self.cell_at(from);
+        let goal = self.cell_at(to);
+        self.cells[start].biome.land_travel_cost()?;
+        self.cells[goal].biome.land_travel_cost()?;
+
+        let goal_position = self.position_of(goal);
+        // The easiest terrain costs 1 per meter, so the straight distance never overestimates the cost.
+        let heuristic = |cell: usize| self.position_of(cell).distance_to(&goal_position, self.radius) as f32;
+
+        let mut costs = vec![f32::INFINITY; self.cells.len()];
+        let mut previous = vec![None; self.cells.len()];
+        let mut frontier = BinaryHeap::new();
+
+        costs[start] = 0.0;
+        frontier.push(Candidate {
+            estimate: heuristic(start),
+            cell: start,
+        });
+
+        while let Some(Candidate { estimate, cell }) = frontier.pop() {
+            if cell == goal {
+                break;
+            }
+            if estimate > costs[cell] + heuristic(cell) {
+                // Already reached more cheaply
+                continue;
+            }
+
+            for &neighbor in self.topology.neighbors(cell) {
+                let Some(step) = self.land_step_cost(cell, neighbor) else {
+                    continue;
+                };
+
+                let cost = costs[cell] + step;
+                if cost < costs[neighbor] {
+                    costs[neighbor] = cost;
+                    previous[neighbor] = Some(cell);
+                    frontier.push(Candidate {
+                        estimate: cost + heuristic(neighbor),
+                        cell: neighbor,
+                    });
+                }
+            }
+        }
+
+        if costs[goal].is_infinite() {
+            return None;
+        }
+
+        let mut cells = vec![goal];
+        while let Some(cell) = previous[*cells.last().unwrap()] {
+            cells.push(cell);
+        }
+        cells.reverse();
+
+        // Start and end at the positions themselves, rather than at the centers of their cells.
+        let mut waypoints = vec![*from];
+        waypoints.extend(cells.iter().skip(1).take(cells.len().saturating_sub(2)).map(|&cell| self.position_of(cell)));
+        waypoints.push(*to);
+
+        let distance = waypoints.windows(2).map(|leg| leg[0].distance_to(&leg[1], self.radius)).sum();
+
+        Some(Route {
+            waypoints,
+            cells,
+            distance,
+            duration: (costs[goal] / walking_speed(traveling_skill)) as u64,
+            mode: TravelMode::Walking,
+        })
+    }
+
+    /// Cost of walking from one cell to a neighboring one, in meters of walking over open grassland that take
+    /// as long. `None` if either cell is water.
+    pub fn land_step_cost(&self, from: usize, to: usize) -> Option<f32> {
+        let (from_cell, to_cell) = (&self.cells[from], &self.cells[to]);
+        let terrain = (from_cell.biome.land_travel_cost()? + to_cell.biome.land_travel_cost()?) / 2.0;
+
+        let distance = self.position_of(from).distance_to(&self.position_of(to), self.radius) as f32;
+        let climb = (to_cell.elevation - from_cell.elevation).max(0.0);
+
+        // Walking along a river follows its valley. Walking onto one from the side means fording it.
+        let along_river = from_cell.downstream == Some(to) || to_cell.downstream == Some(from);
+        let ford = if to_cell.river && !along_river {
+            FORD_DISTANCE * to_cell.flow.sqrt().min(MAX_FORD_FACTOR)
+        } else {
+            0.0
+        };
+
+        Some(distance * terrain + climb * CLIMB_DISTANCE + ford)
+    }
+}
@@ -1,7 +1,17 @@
-type Meters = f64;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI, TAU};
+
+use glam::Vec3A;
+
+use self::notation::CoordinateFormat;
+
+pub type Meters = f64;
+
+/// Mean radius of the Earth, the default radius of generated globes.
+pub const EARTH_RADIUS: Meters = 6_371_000.0;
 
 /// Represents a geographical location with
 /// a tuple containing latitude and longitude in radians.
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub struct Position(f64, f64);
 
 impl Position {
@@ -11,13 +21,258 @@ impl Position {
         Self(phi, lambda)
     }
 
-    pub fn r#move(&mut self, distance: Meters, destination: &Position) {
-        todo!("Implement moving");
+    /// Latitude in radians, positive to the north.
+    pub fn latitude(&self) -> f64 {
+        self.0
+    }
+
+    /// Longitude in radians, positive to the east.
+    pub fn longitude(&self) -> f64 {
+        self.1
+    }
+
+    /// The same position with its latitude in [-90°, 90°] and its longitude wrapped into [-180°, 180°).
+    /// Latitudes past a pole come back down on the other side of it.
+    pub fn normalized(&self) -> Position {
+        let mut phi = wrap_longitude(self.0);
+        let mut lambda = self.1;
+
+        if phi > FRAC_PI_2 {
+            phi = PI - phi;
+            lambda += PI;
+        } else if phi < -FRAC_PI_2 {
+            phi = -PI - phi;
+            lambda += PI;
+        }
+
+        Position(phi, wrap_longitude(lambda))
+    }
+
+    /// Constructs the position of a point on the globe, given as a vector from the center of the globe.
+    /// The north pole lies along +y, and the prime meridian crosses the equator along +x.
+    pub fn from_vector(vector: Vec3A) -> Self {
+        let v = vector.normalize();
+        Self((v.y as f64).clamp(-1.0, 1.0).asin(), (v.z as f64).atan2(v.x as f64))
+    }
+
+    /// The point of this position on the unit sphere, the inverse of `Position::from_vector`.
+    pub fn to_unit_vector(&self) -> Vec3A {
+        let (sin_phi, cos_phi) = self.0.sin_cos();
+        let (sin_lambda, cos_lambda) = self.1.sin_cos();
+        Vec3A::new((cos_phi * cos_lambda) as f32, sin_phi as f32, (cos_phi * sin_lambda) as f32)
+    }
+
+    /// Unit vectors pointing east and north along the surface of the globe at this position.
+    /// At the poles, where east is undefined, the directions of the prime meridian are used.
+    pub fn local_axes(&self) -> (Vec3A, Vec3A) {
+        let (sin_phi, cos_phi) = self.0.sin_cos();
+        let (sin_lambda, cos_lambda) = self.1.sin_cos();
+
+        let east = Vec3A::new(-sin_lambda as f32, 0.0, cos_lambda as f32);
+        let north = Vec3A::new((-sin_phi * cos_lambda) as f32, cos_phi as f32, (-sin_phi * sin_lambda) as f32);
+        (east, north)
+    }
+
+    /// Angle in radians between this position and another, seen from the center of the globe.
+    /// Uses the haversine formula, which stays accurate for positions close to each other.
+    pub fn central_angle_to(&self, other: &Position) -> f64 {
+        let half_delta_phi = (other.0 - self.0) / 2.0;
+        let half_delta_lambda = (other.1 - self.1) / 2.0;
+
+        let h = half_delta_phi.sin().powi(2) + self.0.cos() * other.0.cos() * half_delta_lambda.sin().powi(2);
+        2.0 * h.sqrt().min(1.0).asin()
+    }
+
+    /// Great circle distance to another position on a globe of the given radius.
+    pub fn distance_to(&self, other: &Position, radius: Meters) -> Meters {
+        self.central_angle_to(other) * radius
+    }
+
+    /// Bearing in radians, clockwise from north, to head out on the great circle towards another position.
+    pub fn initial_bearing_to(&self, other: &Position) -> f64 {
+        let delta_lambda = other.1 - self.1;
+        let y = delta_lambda.sin() * other.0.cos();
+        let x = self.0.cos() * other.0.sin() - self.0.sin() * other.0.cos() * delta_lambda.cos();
+        y.atan2(x).rem_euclid(TAU)
+    }
+
+    /// Bearing in radians, clockwise from north, on arrival at another position along the great circle.
+    pub fn final_bearing_to(&self, other: &Position) -> f64 {
+        (other.initial_bearing_to(self) + PI).rem_euclid(TAU)
+    }
+
+    /// The position a fraction of the way along the great circle to another position.
+    pub fn interpolate(&self, other: &Position, fraction: f64) -> Position {
+        let delta = self.central_angle_to(other);
+        if delta < f64::EPSILON {
+            return *self;
+        }
+
+        // Antipodal positions are joined by every meridian, so any bearing leads to the other one.
+        if (PI - delta) < 1e-9 {
+            return self.destination_by_angle(self.initial_bearing_to(other), delta * fraction);
+        }
+
+        let a = ((1.0 - fraction) * delta).sin() / delta.sin();
+        let b = (fraction * delta).sin() / delta.sin();
+
+        let x = a * self.0.cos() * self.1.cos() + b * other.0.cos() * other.1.cos();
+        let y = a * self.0.cos() * self.1.sin() + b * other.0.cos() * other.1.sin();
+        let z = a * self.0.sin() + b * other.0.sin();
+
+        Position(z.atan2(x.hypot(y)), y.atan2(x))
+    }
+
+    /// The position reached by following the great circle that sets out on a bearing (in radians,
+    /// clockwise from north) for a distance, on a globe of the given radius.
+    pub fn destination(&self, bearing: f64, distance: Meters, radius: Meters) -> Position {
+        self.destination_by_angle(bearing, distance / radius)
+    }
+
+    /// Moves the position a distance along the great circle towards a destination, on a globe of the
+    /// given radius. Stops at the destination rather than overshooting it, and returns how much of the
+    /// distance is left over after arriving. Returns zero if the destination hasn't been reached.
+    pub fn r#move(&mut self, distance: Meters, destination: &Position, radius: Meters) -> Meters {
+        let remaining = self.distance_to(destination, radius);
+
+        if distance >= remaining {
+            *self = *destination;
+            return distance - remaining;
+        }
+
+        *self = self.interpolate(destination, distance / remaining);
+        0.0
+    }
+
+    /// Distance to another position along the rhumb line, the path that keeps a constant bearing,
+    /// on a globe of the given radius. Never shorter than the great circle distance.
+    pub fn rhumb_distance_to(&self, other: &Position, radius: Meters) -> Meters {
+        let delta_phi = other.0 - self.0;
+        let delta_lambda = wrap_longitude(other.1 - self.1);
+        let q = self.rhumb_stretch(other);
+
+        (delta_phi * delta_phi + q * q * delta_lambda * delta_lambda).sqrt() * radius
+    }
+
+    /// The constant bearing in radians, clockwise from north, of the rhumb line to another position.
+    pub fn rhumb_bearing_to(&self, other: &Position) -> f64 {
+        let delta_lambda = wrap_longitude(other.1 - self.1);
+        delta_lambda.atan2(mercator_delta(self.0, other.0)).rem_euclid(TAU)
+    }
+
+    /// The position reached by keeping a constant bearing (in radians, clockwise from north) for a distance,
+    /// on a globe of the given radius. A rhumb line spirals into the pole, so it ends there if it gets that far.
+    pub fn rhumb_destination(&self, bearing: f64, distance: Meters, radius: Meters) -> Position {
+        let delta = distance / radius;
+        let phi = self.0 + delta * bearing.cos();
+
+        if phi.abs() >= FRAC_PI_2 {
+            return Position(FRAC_PI_2.copysign(phi), self.1);
+        }
+
+        let end = Position(phi, self.1);
+        let lambda = self.1 + delta * bearing.sin() / self.rhumb_stretch(&end);
+
+        Position(phi, wrap_longitude(lambda))
+    }
+
+    /// Moves the position a distance along the rhumb line towards a destination, on a globe of the given
+    /// radius. Like `Position::move`, returns how much of the distance is left over after arriving.
+    pub fn rhumb_move(&mut self, distance: Meters, destination: &Position, radius: Meters) -> Meters {
+        let remaining = self.rhumb_distance_to(destination, radius);
+
+        if distance >= remaining {
+            *self = *destination;
+            return distance - remaining;
+        }
+
+        *self = self.rhumb_destination(self.rhumb_bearing_to(destination), distance, radius);
+        0.0
+    }
+
+    /// Ratio between the change in latitude and the change in Mercator latitude from this position to another.
+    /// Along a rhumb line, this is the factor east-west angles shrink by.
+    fn rhumb_stretch(&self, other: &Position) -> f64 {
+        let delta_psi = mercator_delta(self.0, other.0);
+
+        // Due east or west, the rhumb line follows the parallel.
+        if delta_psi.abs() > 1e-12 {
+            (other.0 - self.0) / delta_psi
+        } else {
+            self.0.cos()
+        }
+    }
+
+    fn destination_by_angle(&self, bearing: f64, delta: f64) -> Position {
+        let (sin_phi, cos_phi) = self.0.sin_cos();
+        let (sin_delta, cos_delta) = delta.sin_cos();
+
+        let phi = (sin_phi * cos_delta + cos_phi * sin_delta * bearing.cos()).clamp(-1.0, 1.0).asin();
+        let lambda = self.1 + (bearing.sin() * sin_delta * cos_phi).atan2(cos_delta - sin_phi * phi.sin());
+
+        Position(phi, wrap_longitude(lambda))
+    }
+}
+
+/// Difference in latitude between two latitudes, stretched as on the Mercator projection,
+/// on which rhumb lines are straight.
+fn mercator_delta(from: f64, to: f64) -> f64 {
+    ((FRAC_PI_4 + to / 2.0).tan() / (FRAC_PI_4 + from / 2.0).tan()).ln()
+}
+
+/// Wraps an angle in radians, such as a longitude, into the range [-π, π).
+fn wrap_longitude(lambda: f64) -> f64 {
+    (lambda + PI).rem_euclid(TAU) - PI
+}
+
+/// How a traveler steers towards a destination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Course {
+    /// Follows the great circle, the shortest path, whose bearing keeps changing along the way.
+    GreatCircle,
+    /// Follows the rhumb line, a longer path that is simple to steer as it keeps the same bearing.
+    Rhumb,
+}
+
+impl Course {
+    /// Distance from a position to a destination along this course, on a globe of the given radius.
+    pub fn distance(&self, from: &Position, to: &Position, radius: Meters) -> Meters {
+        match self {
+            Course::GreatCircle => from.distance_to(to, radius),
+            Course::Rhumb => from.rhumb_distance_to(to, radius),
+        }
+    }
+
+    /// Bearing to steer at a position to stay on this course to a destination.
+    pub fn bearing(&self, from: &Position, to: &Position) -> f64 {
+        match self {
+            Course::GreatCircle => from.initial_bearing_to(to),
+            Course::Rhumb => from.rhumb_bearing_to(to),
+        }
+    }
+
+    /// Moves a position along this course, returning the distance left over on arrival.
+    pub fn step(&self, position: &mut Position, distance: Meters, destination: &Position, radius: Meters) -> Meters {
+        match self {
+            Course::GreatCircle => position.r#move(distance, destination, radius),
+            Course::Rhumb => position.rhumb_move(distance, destination, radius),
+        }
+    }
+}
+
+impl std::fmt::Display for Course {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Course::GreatCircle => write!(f, "great circle"),
+            Course::Rhumb => write!(f, "rhumb line"),
+        }
     }
 }
 
 impl std::fmt::Display for Position {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{:.3}°, {:.3}°", self.0.to_degrees(), self.1.to_degrees())
+        write!(f, "{}", self.format(CoordinateFormat::Decimal))
     }
 }
+
+pub mod notation;
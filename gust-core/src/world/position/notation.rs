@@ -0,0 +1,255 @@
+use std::{fmt, str::FromStr};
+
+use super::Position;
+
+/// Notation for writing out the latitude and longitude of a position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CoordinateFormat {
+    /// Signed decimal degrees, negative to the south and west: `51.507°, -0.128°`
+    Decimal,
+    /// Decimal degrees with hemispheres: `51.507°N, 0.128°W`
+    DecimalHemisphere,
+    /// Degrees and decimal minutes, as commonly used at sea: `51°30.4'N, 0°07.7'W`
+    DegreesMinutes,
+    /// Degrees, minutes and seconds: `51°30'27"N, 0°07'40"W`
+    DegreesMinutesSeconds,
+}
+
+/// A position written out in some notation, created by `Position::format`.
+/// The precision of the formatter, if given, sets the number of decimals of the last component.
+pub struct FormattedPosition {
+    position: Position,
+    format: CoordinateFormat,
+}
+
+impl Position {
+    /// Writes the position out in the given notation, with its longitude wrapped into [-180°, 180°).
+    pub fn format(&self, format: CoordinateFormat) -> FormattedPosition {
+        FormattedPosition {
+            position: self.normalized(),
+            format,
+        }
+    }
+}
+
+impl fmt::Display for FormattedPosition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let latitude = self.position.latitude().to_degrees();
+        let longitude = self.position.longitude().to_degrees();
+
+        match self.format {
+            CoordinateFormat::Decimal => {
+                let precision = f.precision().unwrap_or(3);
+                write!(f, "{:.*}°, {:.*}°", precision, latitude, precision, longitude)
+            }
+            _ => {
+                write_component(f, self.format, latitude, ['N', 'S'])?;
+                write!(f, ", ")?;
+                write_component(f, self.format, longitude, ['E', 'W'])
+            }
+        }
+    }
+}
+
+/// Writes an angle in degrees with its hemisphere letter, positive or negative.
+fn write_component(f: &mut fmt::Formatter<'_>, format: CoordinateFormat, degrees: f64, [positive, negative]: [char; 2]) -> fmt::Result {
+    let hemisphere = if degrees < 0.0 { negative } else { positive };
+    let degrees = degrees.abs();
+
+    match format {
+        CoordinateFormat::Decimal | CoordinateFormat::DecimalHemisphere => {
+            let precision = f.precision().unwrap_or(3);
+            write!(f, "{:.*}°{}", precision, degrees, hemisphere)
+        }
+        CoordinateFormat::DegreesMinutes => {
+            let precision = f.precision().unwrap_or(1);
+            // Round before splitting so that 59.96' doesn't come out as 60.0'.
+            let minutes = round_to(degrees * 60.0, precision);
+            let whole_degrees = (minutes / 60.0).floor();
+            let minutes = minutes - whole_degrees * 60.0;
+            let width = if precision == 0 { 2 } else { precision + 3 };
+            write!(f, "{}°{:0width$.precision$}'{}", whole_degrees, minutes, hemisphere)
+        }
+        CoordinateFormat::DegreesMinutesSeconds => {
+            let precision = f.precision().unwrap_or(0);
+            let seconds = round_to(degrees * 3600.0, precision);
+            let whole_degrees = (seconds / 3600.0).floor();
+            let whole_minutes = ((seconds - whole_degrees * 3600.0) / 60.0).floor();
+            let seconds = seconds - whole_degrees * 3600.0 - whole_minutes * 60.0;
+            let width = if precision == 0 { 2 } else { precision + 3 };
+            write!(f, "{}°{:02}'{:0width$.precision$}\"{}", whole_degrees, whole_minutes, seconds, hemisphere)
+        }
+    }
+}
+
+fn round_to(value: f64, decimals: usize) -> f64 {
+    let scale = 10f64.powi(decimals as i32);
+    (value * scale).round() / scale
+}
+
+/// Reasons a position couldn't be read from text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePositionError {
+    /// The text doesn't hold both a latitude and a longitude.
+    MissingCoordinate,
+    /// A coordinate has more than degrees, minutes and seconds, or more than one hemisphere.
+    TooManyParts,
+    InvalidNumber(String),
+    UnexpectedCharacter(char),
+    /// A hemisphere letter was used for the wrong coordinate, such as E for a latitude.
+    WrongHemisphere(char),
+    /// Minutes or seconds of 60 or more, or a latitude beyond the poles.
+    OutOfRange,
+}
+
+impl fmt::Display for ParsePositionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsePositionError::MissingCoordinate => write!(f, "expected a latitude and a longitude"),
+            ParsePositionError::TooManyParts => write!(f, "too many parts in a coordinate"),
+            ParsePositionError::InvalidNumber(number) => write!(f, "invalid number \"{}\"", number),
+            ParsePositionError::UnexpectedCharacter(c) => write!(f, "unexpected character '{}'", c),
+            ParsePositionError::WrongHemisphere(hemisphere) => write!(f, "hemisphere {} doesn't fit that coordinate", hemisphere),
+            ParsePositionError::OutOfRange => write!(f, "coordinate out of range"),
+        }
+    }
+}
+
+impl std::error::Error for ParsePositionError {}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+    Number(String),
+    Hemisphere(char),
+}
+
+impl FromStr for Position {
+    type Err = ParsePositionError;
+
+    /// Reads a latitude followed by a longitude, in degrees. Each coordinate is either signed, or
+    /// unsigned with a hemisphere letter before or after it, and may be split into degrees, minutes
+    /// and seconds separated by spaces or by °, ' and " marks. The coordinates can be separated by a comma.
+    ///
+    /// All of these read the same position:
+    /// `51.5074, -0.1278`, `51.5074 N 0.1278 W`, `51°30.44'N 0°07.67'W`, `N 51 30 26.6 W 0 7 40.1`
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (latitude, longitude) = match text.split_once(',') {
+            Some((latitude, longitude)) => (tokenize(latitude)?, tokenize(longitude)?),
+            None => split_coordinates(tokenize(text)?)?,
+        };
+
+        let latitude = parse_coordinate(&latitude, ['N', 'S'])?;
+        let longitude = parse_coordinate(&longitude, ['E', 'W'])?;
+
+        if latitude.abs() > 90.0 {
+            return Err(ParsePositionError::OutOfRange);
+        }
+
+        Ok(Position::new(latitude.to_radians(), longitude.to_radians()).normalized())
+    }
+}
+
+fn tokenize(text: &str) -> Result<Vec<Token>, ParsePositionError> {
+    let mut tokens = Vec::new();
+    let mut number = String::new();
+
+    for c in text.chars() {
+        if c.is_ascii_digit() || c == '.' || c == '-' || c == '+' {
+            number.push(c);
+            continue;
+        }
+
+        if !number.is_empty() {
+            tokens.push(Token::Number(std::mem::take(&mut number)));
+        }
+
+        match c.to_ascii_uppercase() {
+            hemisphere @ ('N' | 'S' | 'E' | 'W') => tokens.push(Token::Hemisphere(hemisphere)),
+            '°' | '\'' | '"' | '′' | '″' | 'º' => (),
+            c if c.is_whitespace() => (),
+            _ => return Err(ParsePositionError::UnexpectedCharacter(c)),
+        }
+    }
+
+    if !number.is_empty() {
+        tokens.push(Token::Number(number));
+    }
+
+    Ok(tokens)
+}
+
+/// Splits the tokens of text without a comma into the latitude and the longitude.
+fn split_coordinates(tokens: Vec<Token>) -> Result<(Vec<Token>, Vec<Token>), ParsePositionError> {
+    let hemispheres: Vec<usize> = tokens
+        .iter()
+        .enumerate()
+        .filter(|(_, token)| matches!(token, Token::Hemisphere(_)))
+        .map(|(index, _)| index)
+        .collect();
+
+    let split = match hemispheres.as_slice() {
+        // Without hemispheres, the numbers are shared evenly between the two coordinates.
+        [] if tokens.len().is_multiple_of(2) => tokens.len() / 2,
+        [] => return Err(ParsePositionError::MissingCoordinate),
+        // Hemispheres written before each coordinate
+        [0, second] => *second,
+        // Hemispheres written after each coordinate
+        [first, _] => first + 1,
+        [_] => return Err(ParsePositionError::MissingCoordinate),
+        _ => return Err(ParsePositionError::TooManyParts),
+    };
+
+    let mut latitude = tokens;
+    let longitude = latitude.split_off(split);
+    Ok((latitude, longitude))
+}
+
+/// Reads a coordinate in degrees from its tokens, negative if it lies in the second of the given hemispheres.
+fn parse_coordinate(tokens: &[Token], [positive, negative]: [char; 2]) -> Result<f64, ParsePositionError> {
+    let mut parts = Vec::with_capacity(3);
+    let mut sign = 1.0;
+    let mut has_hemisphere = false;
+
+    for token in tokens {
+        match token {
+            Token::Number(number) => {
+                let value: f64 = number.parse().map_err(|_| ParsePositionError::InvalidNumber(number.clone()))?;
+                if !value.is_finite() {
+                    return Err(ParsePositionError::InvalidNumber(number.clone()));
+                }
+                parts.push(value);
+            }
+            Token::Hemisphere(hemisphere) => {
+                if has_hemisphere {
+                    return Err(ParsePositionError::TooManyParts);
+                }
+                has_hemisphere = true;
+
+                if *hemisphere == negative {
+                    sign = -1.0;
+                } else if *hemisphere != positive {
+                    return Err(ParsePositionError::WrongHemisphere(*hemisphere));
+                }
+            }
+        }
+    }
+
+    let (degrees, minutes, seconds) = match parts.as_slice() {
+        [] => return Err(ParsePositionError::MissingCoordinate),
+        [degrees] => (*degrees, 0.0, 0.0),
+        [degrees, minutes] => (*degrees, *minutes, 0.0),
+        [degrees, minutes, seconds] => (*degrees, *minutes, *seconds),
+        _ => return Err(ParsePositionError::TooManyParts),
+    };
+
+    // Only the degrees may carry a sign, and not along with a hemisphere.
+    if !(0.0..60.0).contains(&minutes) || !(0.0..60.0).contains(&seconds) {
+        return Err(ParsePositionError::OutOfRange);
+    }
+    if has_hemisphere && degrees < 0.0 {
+        return Err(ParsePositionError::OutOfRange);
+    }
+
+    let magnitude = degrees.abs() + minutes / 60.0 + seconds / 3600.0;
+    Ok(sign * magnitude.copysign(degrees))
+}
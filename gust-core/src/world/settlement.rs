@@ -0,0 +1,201 @@
+use std::collections::{HashMap, HashSet};
+
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+use super::{
+    globe::{Globe, Surface},
+    position::{Meters, Position},
+};
+
+/// Share added to the score of a cell for lying on the coast, where there is fish and trade over the sea.
+const COAST_SCORE: f32 = 0.6;
+/// Share added to the score of a cell for the river running through it, for water, fish and trade upstream.
+const RIVER_SCORE: f32 = 0.5;
+/// Score below which a cell is too poor to settle.
+const MIN_SCORE: f32 = 0.15;
+/// Factor the score of the cells two steps from a settlement is scaled by, as the land there is already farmed from it.
+/// The cells right next to it are never settled.
+const SPACING_FACTOR: f32 = 0.4;
+/// People living in a settlement on a cell of the highest score, before the rest of the land is settled around it.
+const BEST_POPULATION: f32 = 60_000.0;
+/// Least people in a town and in a city.
+const TOWN_POPULATION: u32 = 2_000;
+const CITY_POPULATION: u32 = 20_000;
+
+/// Syllables names are put together from, and the endings of the names of inland and harbor settlements.
+const SYLLABLES: [&str; 24] = [
+    "al", "bar", "cor", "dun", "el", "fen", "gar", "hal", "is", "kel", "lin", "mar", "nor", "os", "pel", "quen", "ros", "sal", "tor", "ul", "var", "wen", "yr", "zan",
+];
+const INLAND_ENDINGS: [&str; 8] = ["ford", "by", "ton", "wick", "stead", "burg", "dale", "field"];
+const HARBOR_ENDINGS: [&str; 6] = ["haven", "port", "mouth", "wick", "holm", "sund"];
+
+/// How big a settlement is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SettlementKind {
+    Village,
+    Town,
+    City,
+}
+
+impl SettlementKind {
+    /// The kind of settlement of the given population.
+    pub fn of_population(population: u32) -> Self {
+        if population >= CITY_POPULATION {
+            SettlementKind::City
+        } else if population >= TOWN_POPULATION {
+            SettlementKind::Town
+        } else {
+            SettlementKind::Village
+        }
+    }
+}
+
+impl std::fmt::Display for SettlementKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            SettlementKind::Village => "Village",
+            SettlementKind::Town => "Town",
+            SettlementKind::City => "City",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A village, town or city, where people live and trade.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Settlement {
+    pub name: String,
+    pub kind: SettlementKind,
+    pub population: u32,
+    pub position: Position,
+    /// The cell the settlement stands on.
+    pub cell: usize,
+    /// Whether the settlement lies on the coast, with a harbor ships can put in at.
+    pub harbor: bool,
+}
+
+/// All the settlements on the globe, in the order they were settled, from the best land down.
+#[derive(Clone, Debug, Default)]
+pub struct Settlements {
+    settlements: Vec<Settlement>,
+    /// Index into `settlements` of the settlement on each settled cell.
+    by_cell: HashMap<usize, usize>,
+}
+
+impl Settlements {
+    /// Settles the land of the globe, at most once on each cell. Each cell is scored by how fertile it is, and
+    /// more so on the coast and along rivers. The best cells are settled first, and each settlement rules out the
+    /// cells next to it and makes those beyond less worth settling, so that the settlements spread out. Cells too
+    /// poor to live on stay empty.
+    pub fn generate(globe: &Globe, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut scores: Vec<f32> = (0..globe.cells.len()).map(|cell| settling_score(globe, cell)).collect();
+        let best = scores.iter().copied().fold(MIN_SCORE, f32::max);
+
+        let mut this = Self::default();
+        let mut names = HashSet::new();
+        while let Some((cell, score)) = scores
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, score)| score >= MIN_SCORE)
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+        {
+            scores[cell] = 0.0;
+            let neighbors = globe.topology.neighbors(cell);
+            let around: HashSet<usize> = neighbors.iter().flat_map(|&neighbor| globe.topology.neighbors(neighbor).iter().copied()).collect();
+            for farther in around {
+                scores[farther] *= SPACING_FACTOR;
+            }
+            for &neighbor in neighbors {
+                scores[neighbor] = 0.0;
+            }
+
+            let harbor = is_coastal(globe, cell);
+            let population = (BEST_POPULATION * (score / best).powi(3) * rng.gen_range(0.5..1.5)) as u32;
+            let name = loop {
+                let name = settlement_name(&mut rng, harbor);
+                if names.insert(name.clone()) {
+                    break name;
+                }
+            };
+
+            this.by_cell.insert(cell, this.settlements.len());
+            this.settlements.push(Settlement {
+                name,
+                kind: SettlementKind::of_population(population),
+                population,
+                position: globe.position_of(cell),
+                cell,
+                harbor,
+            });
+        }
+
+        this
+    }
+
+    /// The settlement standing on a cell, if any.
+    pub fn at_cell(&self, cell: usize) -> Option<&Settlement> {
+        self.by_cell.get(&cell).map(|&index| &self.settlements[index])
+    }
+
+    /// The settlement nearest a position, or `None` if there are none at all.
+    pub fn nearest(&self, position: &Position) -> Option<&Settlement> {
+        self.settlements
+            .iter()
+            .min_by(|a, b| position.central_angle_to(&a.position).total_cmp(&position.central_angle_to(&b.position)))
+    }
+
+    /// The settlements within a distance of a position, on a globe of the given radius, nearest first.
+    pub fn within(&self, position: &Position, distance: Meters, radius: Meters) -> Vec<&Settlement> {
+        let mut nearby: Vec<(Meters, &Settlement)> = self
+            .settlements
+            .iter()
+            .map(|settlement| (position.distance_to(&settlement.position, radius), settlement))
+            .filter(|&(away, _)| away <= distance)
+            .collect();
+        nearby.sort_by(|a, b| a.0.total_cmp(&b.0));
+        nearby.into_iter().map(|(_, settlement)| settlement).collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Settlement> {
+        self.settlements.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.settlements.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.settlements.is_empty()
+    }
+}
+
+/// How worth settling a cell is: how well its land feeds people, with more for lying on the coast and on a river.
+/// Zero for water.
+fn settling_score(globe: &Globe, cell: usize) -> f32 {
+    let data = &globe.cells[cell];
+    if data.surface != Surface::Land {
+        return 0.0;
+    }
+
+    let coast = if is_coastal(globe, cell) { COAST_SCORE } else { 0.0 };
+    let river = if data.river { RIVER_SCORE } else { 0.0 };
+    data.biome.fertility() * (1.0 + coast + river)
+}
+
+/// Whether a cell of land borders the sea.
+fn is_coastal(globe: &Globe, cell: usize) -> bool {
+    globe.topology.neighbors(cell).iter().any(|&neighbor| globe.cells[neighbor].surface == Surface::Ocean)
+}
+
+/// Makes up a name of two or three syllables, with an ending that suits a harbor or an inland place.
+fn settlement_name(rng: &mut StdRng, harbor: bool) -> String {
+    let endings: &[&str] = if harbor { &HARBOR_ENDINGS } else { &INLAND_ENDINGS };
+    let syllables = rng.gen_range(1..=2);
+    let mut name: String = (0..syllables).map(|_| *SYLLABLES.choose(rng).unwrap()).collect();
+    name.push_str(endings.choose(rng).unwrap());
+
+    let mut letters = name.chars();
+    letters.next().map(|first| first.to_uppercase().chain(letters).collect()).unwrap_or_default()
+}
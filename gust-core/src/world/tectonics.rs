@@ -0,0 +1,231 @@
+use std::collections::VecDeque;
+
+use glam::Vec3A;
+use rand::{rngs::StdRng, seq::index::sample, Rng, SeedableRng};
+
+use super::globe::Globe;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PlateKind {
+    /// Thick, buoyant crust that carries the continents.
+    Continental,
+    /// Thin, dense crust that lies under the deep sea.
+    Oceanic,
+}
+
+/// A rigid piece of crust rotating around an axis through the center of the globe.
+#[derive(Clone, Debug)]
+pub struct Plate {
+    pub kind: PlateKind,
+    /// Axis of rotation of the plate, as a unit vector.
+    pub axis: Vec3A,
+    /// Rotation speed of the plate around its axis, in radians per unit of simulated time.
+    pub angular_speed: f32,
+}
+
+impl Plate {
+    /// Velocity of the plate at a point on the unit sphere. The result is tangent to the sphere.
+    pub fn velocity_at(&self, point: Vec3A) -> Vec3A {
+        self.axis.cross(point) * self.angular_speed
+    }
+}
+
+/// Parameters of the plate tectonics world generation pass.
+#[derive(Clone, Debug)]
+pub struct TectonicParams {
+    pub plate_count: usize,
+    /// Fraction of plates that are continental rather than oceanic.
+    pub continental_fraction: f32,
+    /// Factor the noise elevation is scaled by before plate effects are added on top.
+    pub noise_weight: f32,
+    /// Elevation in meters added to every cell of a continental plate.
+    pub continental_base: f32,
+    /// Elevation in meters added to every cell of an oceanic plate.
+    pub oceanic_base: f32,
+    /// Elevation change in meters along a boundary where plates meet head on at full speed.
+    pub boundary_uplift: f32,
+    /// How many cells away from a boundary its effects reach.
+    pub boundary_reach: usize,
+    /// Fraction of a boundary's effect that is kept with each cell of distance from it.
+    pub boundary_falloff: f32,
+}
+
+impl Default for TectonicParams {
+    fn default() -> Self {
+        Self {
+            plate_count: 14,
+            continental_fraction: 0.4,
+            noise_weight: 0.5,
+            continental_base: 900.0,
+            oceanic_base: -2500.0,
+            boundary_uplift: 3500.0,
+            boundary_reach: 3,
+            boundary_falloff: 0.55,
+        }
+    }
+}
+
+impl Globe {
+    /// Partitions the cells into tectonic plates with random motions, then raises mountain ranges and
+    /// island arcs where plates converge, and opens rifts and ridges where they pull apart.
+    /// Replaces `Globe::plates` and the plate of each cell.
+    pub fn simulate_tectonics(&mut self, seed: u64, params: &TectonicParams) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let plate_count = params.plate_count.clamp(1, self.cells.len());
+
+        self.plates = (0..plate_count)
+            .map(|_| Plate {
+                kind: if rng.gen::<f32>() < params.continental_fraction {
+                    PlateKind::Continental
+                } else {
+                    PlateKind::Oceanic
+                },
+                axis: random_unit_vector(&mut rng),
+                angular_speed: rng.gen_range(0.2..1.0),
+            })
+            .collect();
+
+        let owners = self.grow_plates(&mut rng, plate_count);
+        for (cell, owner) in self.cells.iter_mut().zip(&owners) {
+            cell.plate = Some(*owner);
+        }
+
+        let points = self.data.raw_points();
+        let boundary_effects: Vec<Option<f32>> = (0..self.cells.len())
+            .map(|index| self.boundary_effect(index, &owners, points, params.boundary_uplift))
+            .collect();
+
+        let spread_effects = self.spread_from_boundaries(&boundary_effects, &owners, params);
+
+        for (index, cell) in self.cells.iter_mut().enumerate() {
+            let base = match self.plates[owners[index]].kind {
+                PlateKind::Continental => params.continental_base,
+                PlateKind::Oceanic => params.oceanic_base,
+            };
+            cell.elevation = cell.elevation * params.noise_weight + base + spread_effects[index];
+        }
+    }
+
+    /// Grows the plates outwards from random seed cells, claiming a random cell of the frontier at
+    /// each step so that the plates end up with irregular shapes. Returns the plate of each cell.
+    fn grow_plates(&self, rng: &mut StdRng, plate_count: usize) -> Vec<usize> {
+        let mut owners = vec![usize::MAX; self.cells.len()];
+        let mut frontier = Vec::new();
+
+        for (plate, cell) in sample(rng, self.cells.len(), plate_count).into_iter().enumerate() {
+            owners[cell] = plate;
+            frontier.push(cell);
+        }
+
+        while !frontier.is_empty() {
+            let cell = frontier.swap_remove(rng.gen_range(0..frontier.len()));
+
+            for &neighbor in self.topology.neighbors(cell) {
+                if owners[neighbor] == usize::MAX {
+                    owners[neighbor] = owners[cell];
+                    frontier.push(neighbor);
+                }
+            }
+        }
+
+        owners
+    }
+
+    /// Elevation change at a cell caused by the plate boundary it lies on, or `None` if the cell
+    /// isn't on a boundary.
+    fn boundary_effect(&self, index: usize, owners: &[usize], points: &[Vec3A], uplift: f32) -> Option<f32> {
+        let own_index = owners[index];
+        let own = &self.plates[own_index];
+        let point = points[index];
+
+        let mut total = 0.0;
+        let mut count = 0;
+
+        for &neighbor in self.topology.neighbors(index) {
+            let other_index = owners[neighbor];
+            if other_index == own_index {
+                continue;
+            }
+            let other = &self.plates[other_index];
+
+            // Positive when the plates move towards each other across the boundary, negative when they move apart.
+            let direction = (points[neighbor] - point).normalize();
+            let convergence = (own.velocity_at(point) - other.velocity_at(point)).dot(direction);
+
+            let effect = if convergence > 0.0 {
+                match (own.kind, other.kind) {
+                    // Collision of continents piles up great mountain ranges on both sides.
+                    (PlateKind::Continental, PlateKind::Continental) => 1.0,
+                    // The continent overrides the sinking ocean floor and coastal mountains rise.
+                    (PlateKind::Continental, PlateKind::Oceanic) => 0.8,
+                    // The ocean floor sinks under the continent, leaving a trench.
+                    (PlateKind::Oceanic, PlateKind::Continental) => -0.5,
+                    // One ocean floor sinks under the other, which rises into an arc of volcanic islands.
+                    (PlateKind::Oceanic, PlateKind::Oceanic) => {
+                        if own_index < other_index {
+                            1.1
+                        } else {
+                            -0.5
+                        }
+                    }
+                }
+            } else {
+                match own.kind {
+                    // Continents pulling apart sink into rift valleys, which may flood into straits.
+                    PlateKind::Continental => 0.6,
+                    // Ocean floors pulling apart form mid-ocean ridges.
+                    PlateKind::Oceanic => -0.4,
+                }
+            };
+
+            total += effect * convergence * uplift;
+            count += 1;
+        }
+
+        if count == 0 {
+            None
+        } else {
+            Some(total / count as f32)
+        }
+    }
+
+    /// Spreads the effects of the boundaries into the plates, weakening with distance.
+    /// Each cell receives the effect of the nearest boundary cell of its own plate.
+    fn spread_from_boundaries(&self, boundary_effects: &[Option<f32>], owners: &[usize], params: &TectonicParams) -> Vec<f32> {
+        let mut effects = vec![0.0; self.cells.len()];
+        let mut distances = vec![usize::MAX; self.cells.len()];
+        let mut queue = VecDeque::new();
+
+        for (index, effect) in boundary_effects.iter().enumerate() {
+            if let Some(effect) = effect {
+                effects[index] = *effect;
+                distances[index] = 0;
+                queue.push_back(index);
+            }
+        }
+
+        while let Some(cell) = queue.pop_front() {
+            if distances[cell] >= params.boundary_reach {
+                continue;
+            }
+
+            for &neighbor in self.topology.neighbors(cell) {
+                if distances[neighbor] == usize::MAX && owners[neighbor] == owners[cell] {
+                    distances[neighbor] = distances[cell] + 1;
+                    effects[neighbor] = effects[cell] * params.boundary_falloff;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        effects
+    }
+}
+
+fn random_unit_vector(rng: &mut StdRng) -> Vec3A {
+    // Uniformly distributed over the sphere
+    let z: f32 = rng.gen_range(-1.0..1.0);
+    let theta: f32 = rng.gen_range(0.0..std::f32::consts::TAU);
+    let r = (1.0 - z * z).sqrt();
+    Vec3A::new(r * theta.cos(), r * theta.sin(), z)
+}
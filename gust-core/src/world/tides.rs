@@ -0,0 +1,200 @@
+use std::f64::consts::TAU;
+
+use glam::Vec3A;
+
+use crate::util::noise::Perlin;
+
+use super::{
+    globe::{Globe, Surface},
+    position::{Meters, Position},
+};
+
+/// Strength of the tide raised by the sun, as a fraction of the tide raised by the moon.
+const SOLAR_TIDE_RATIO: f32 = 0.46;
+/// Rise and fall of the tide in meters at spring tides, on an open coast facing deep water.
+const BASE_RANGE: f32 = 0.6;
+/// Extra range in meters where the tide runs in over a shallow shelf, and where it is funnelled into a bay.
+const SHELF_RANGE: f32 = 2.5;
+const BAY_RANGE: f32 = 2.5;
+/// Sea floor shallower than this, in meters, is the shelf that the tide piles up over.
+const SHELF_DEPTH: f32 = -600.0;
+/// Depth in meters at mean sea level of the channel into a harbor without any deep water beside it,
+/// and the extra depth when it is surrounded by deep water.
+const MIN_CHANNEL_DEPTH: f32 = 3.0;
+const DEEP_CHANNEL_DEPTH: f32 = 9.0;
+/// Water in meters a ship should keep under its keel when crossing into or out of a harbor.
+const UNDER_KEEL_CLEARANCE: f32 = 0.5;
+/// Longest delay in seconds of high water behind the moon passing overhead, from the time the tide
+/// takes to travel around the coasts.
+const MAX_TIDE_LAG: f32 = 12.0 * 60.0 * 60.0;
+/// Seconds between the moments searched for a tide high enough to pass, and the longest search.
+const SEARCH_STEP: u64 = 10 * 60;
+const SEARCH_SPAN: u64 = 2 * 24 * 60 * 60;
+/// Seconds on either side of a moment the height of the tide is compared against, to tell which way it is going.
+const SLACK_WINDOW: u64 = 30 * 60;
+
+/// Which way the tide is going at some moment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum TidePhase {
+    /// Flood tide, as the water rises.
+    Rising,
+    /// High water, as the tide turns from rising to falling.
+    High,
+    /// Ebb tide, as the water falls.
+    Falling,
+    /// Low water, as the tide turns from falling to rising.
+    Low,
+}
+
+impl std::fmt::Display for TidePhase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            TidePhase::Rising => "Rising",
+            TidePhase::High => "High water",
+            TidePhase::Falling => "Falling",
+            TidePhase::Low => "Low water",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The tide at a coastal cell at some moment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TideState {
+    /// Height of the water in meters above mean sea level, negative below it.
+    pub height: f32,
+    pub phase: TidePhase,
+}
+
+/// How the tide behaves at a coastal cell.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Coast {
+    /// Rise and fall of the tide at spring tides, in meters.
+    pub spring_range: f32,
+    /// Seconds high water comes after the moon passes overhead.
+    pub lag: u64,
+    /// Depth of the channel into a harbor here at mean sea level, in meters.
+    pub channel_depth: f32,
+}
+
+/// The tides of every coastal cell of a globe, raised by the moon and the sun.
+///
+/// The water is heaped up under the moon and the sun and on the far side of the globe from them,
+/// giving two high tides a day. Tides run highest at spring tides, when the sun and moon pull in line at
+/// new and full moon, and lowest at neap tides in between. Shallow shelves and bays raise the range.
+pub struct Tides {
+    coasts: Vec<Option<Coast>>,
+}
+
+impl Tides {
+    /// Works out the tides of the coasts of a globe, with the delays of high water along them drawn from the seed.
+    pub fn new(globe: &Globe, seed: u64) -> Self {
+        let noise = Perlin::new(seed);
+
+        let coasts = (0..globe.cells.len())
+            .map(|cell| {
+                let neighbors = globe.topology.neighbors(cell);
+                let ocean_neighbors: Vec<usize> = neighbors.iter().copied().filter(|&n| globe.cells[n].surface == Surface::Ocean).collect();
+                let is_coastal = match globe.cells[cell].surface {
+                    Surface::Ocean => ocean_neighbors.len() < neighbors.len(),
+                    Surface::Land => !ocean_neighbors.is_empty(),
+                    Surface::Lake => false,
+                };
+                if !is_coastal {
+                    return None;
+                }
+
+                let sea: Vec<f32> = ocean_neighbors
+                    .iter()
+                    .chain(std::iter::once(&cell).filter(|&&cell| globe.cells[cell].surface == Surface::Ocean))
+                    .map(|&n| globe.cells[n].elevation)
+                    .collect();
+                let shelf_fraction = sea.iter().filter(|&&depth| depth > SHELF_DEPTH).count() as f32 / sea.len() as f32;
+                let enclosure = 1.0 - ocean_neighbors.len() as f32 / neighbors.len() as f32;
+
+                // Smooth along the coasts, so neighboring harbors see high water at about the same time.
+                let point = globe.topology.centroid(cell) * 2.0;
+                let lag_fraction = (noise.get(point.x, point.y, point.z) + 1.0) / 2.0;
+
+                Some(Coast {
+                    spring_range: BASE_RANGE + SHELF_RANGE * shelf_fraction + BAY_RANGE * enclosure,
+                    lag: (lag_fraction.clamp(0.0, 1.0) * MAX_TIDE_LAG) as u64,
+                    channel_depth: MIN_CHANNEL_DEPTH + DEEP_CHANNEL_DEPTH * (1.0 - shelf_fraction),
+                })
+            })
+            .collect();
+
+        Self { coasts }
+    }
+
+    /// How the tide behaves at a cell, if it lies on a coast of the sea.
+    pub fn coast(&self, cell: usize) -> Option<&Coast> {
+        self.coasts[cell].as_ref()
+    }
+
+    /// Height of the tide at a coastal cell in meters above mean sea level, or `None` away from the coasts.
+    pub fn height(&self, globe: &Globe, cell: usize, time_in_seconds: u64) -> Option<f32> {
+        let coast = self.coast(cell)?;
+        let astronomy = &globe.astronomy;
+
+        // The tide here is the one the moon and sun raised a while ago. As the bulges sweep west around the
+        // globe with the day, that is the tide they are raising now at a point further west.
+        let position = globe.position_of(cell);
+        let lag_angle = TAU * coast.lag as f64 / astronomy.day_length as f64;
+        let point = Position::new(position.latitude(), position.longitude() - lag_angle).to_unit_vector();
+
+        let lunar = tidal_bulge(point, astronomy.sublunar_point(time_in_seconds).to_unit_vector());
+        let solar = tidal_bulge(point, astronomy.subsolar_point(time_in_seconds).to_unit_vector());
+        // Mean of the bulges over a day at this latitude, with the moon and sun over the equator,
+        // which sets the mean sea level the tide rises above and falls below.
+        let mean = 0.75 * (1.0 - point.y * point.y) - 0.5;
+
+        // The bulges reach from -0.5 to 1, so the highest spring tides rise and fall by the full spring range.
+        let bulge = (lunar - mean) + SOLAR_TIDE_RATIO * (solar - mean);
+        Some(coast.spring_range * bulge / (1.5 * (1.0 + SOLAR_TIDE_RATIO)))
+    }
+
+    /// Height of the tide and which way it is going at a coastal cell, or `None` away from the coasts.
+    pub fn state(&self, globe: &Globe, cell: usize, time_in_seconds: u64) -> Option<TideState> {
+        let height = self.height(globe, cell, time_in_seconds)?;
+        let before = self.height(globe, cell, time_in_seconds.saturating_sub(SLACK_WINDOW))?;
+        let after = self.height(globe, cell, time_in_seconds + SLACK_WINDOW)?;
+
+        let phase = if height >= before && height >= after {
+            TidePhase::High
+        } else if height <= before && height <= after {
+            TidePhase::Low
+        } else if after > before {
+            TidePhase::Rising
+        } else {
+            TidePhase::Falling
+        };
+
+        Some(TideState { height, phase })
+    }
+
+    /// Whether a ship drawing the given depth of water can cross into or out of a harbor at a coastal cell.
+    /// Cells away from the coasts have no harbor channel to cross.
+    pub fn can_pass(&self, globe: &Globe, cell: usize, time_in_seconds: u64, draft: Meters) -> bool {
+        match (self.coast(cell), self.height(globe, cell, time_in_seconds)) {
+            (Some(coast), Some(height)) => coast.channel_depth + height >= draft as f32 + UNDER_KEEL_CLEARANCE,
+            _ => true,
+        }
+    }
+
+    /// The first moment from the given one at which a ship drawing the given depth of water can cross into
+    /// or out of a harbor at a coastal cell. `None` if the water never rises high enough over the next
+    /// couple of days, as when the ship is too deep for the harbor at any tide.
+    pub fn next_passable(&self, globe: &Globe, cell: usize, time_in_seconds: u64, draft: Meters) -> Option<u64> {
+        (0..=SEARCH_SPAN / SEARCH_STEP)
+            .map(|step| time_in_seconds + step * SEARCH_STEP)
+            .find(|&time| self.can_pass(globe, cell, time, draft))
+    }
+}
+
+/// Height of the tidal bulge raised at a point by a body standing overhead at another point, both on the unit
+/// sphere: 1 right under the body and on the far side of the globe, and -0.5 halfway between.
+fn tidal_bulge(point: Vec3A, body: Vec3A) -> f32 {
+    let cosine = point.dot(body);
+    (3.0 * cosine * cosine - 1.0) / 2.0
+}
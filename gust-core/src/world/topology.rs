@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+
+use glam::Vec3A;
+use hexasphere::shapes::IcoSphere;
+
+/// The border shared by two neighboring cells.
+#[derive(Clone, Debug)]
+pub struct Edge {
+    /// The two cells on either side of the edge, the lower index first.
+    pub cells: [usize; 2],
+    /// Endpoints of the edge on the unit sphere. These are corners of both cells.
+    pub endpoints: [Vec3A; 2],
+    /// Length of the edge along the surface of the unit sphere.
+    pub length: f32,
+}
+
+impl Edge {
+    /// The cell on the other side of the edge from the given one.
+    pub fn other(&self, cell: usize) -> usize {
+        if self.cells[0] == cell {
+            self.cells[1]
+        } else {
+            self.cells[0]
+        }
+    }
+}
+
+/// Adjacency of the cells of a globe. Each point of the icosphere is the center of a hexagonal
+/// (or, for twelve of them, pentagonal) cell whose corners are the centers of the surrounding triangles.
+/// All lengths and areas are measured on the unit sphere.
+pub struct Topology {
+    /// Corner indices of each triangle of the icosphere, wound counter clockwise.
+    triangles: Vec<[usize; 3]>,
+    /// Neighbors of each cell, wound counter clockwise around it.
+    neighbors: Vec<Vec<usize>>,
+    /// Corners of each cell, wound counter clockwise. Neighbor `i` lies across the edge from corner `i` to corner `i + 1`.
+    corners: Vec<Vec<Vec3A>>,
+    /// Triangles each cell is a corner of.
+    cell_triangles: Vec<Vec<usize>>,
+    edges: Vec<Edge>,
+    /// Indices into `edges` of the edges of each cell, in the same order as its neighbors.
+    cell_edges: Vec<Vec<usize>>,
+    centroids: Vec<Vec3A>,
+    areas: Vec<f32>,
+}
+
+impl Topology {
+    pub fn new(shape: &IcoSphere<()>) -> Self {
+        let points = shape.raw_points();
+        let cell_count = points.len();
+
+        let triangles: Vec<[usize; 3]> = shape
+            .get_all_indices()
+            .chunks(3)
+            .map(|triangle| [triangle[0] as usize, triangle[1] as usize, triangle[2] as usize])
+            .collect();
+        let triangle_centers: Vec<Vec3A> = triangles.iter().map(|&[a, b, c]| (points[a] + points[b] + points[c]).normalize()).collect();
+
+        let mut cell_triangles = vec![Vec::with_capacity(6); cell_count];
+        for (index, triangle) in triangles.iter().enumerate() {
+            for &corner in triangle {
+                cell_triangles[corner].push(index);
+            }
+        }
+
+        // Wind the triangles around each cell by their angle in the plane tangent to the cell's center.
+        for (cell, incident) in cell_triangles.iter_mut().enumerate() {
+            let normal = points[cell];
+            let tangent = normal.any_orthonormal_vector();
+            let bitangent = normal.cross(tangent);
+            let angle = |triangle: &usize| {
+                let direction = triangle_centers[*triangle] - normal;
+                direction.dot(bitangent).atan2(direction.dot(tangent))
+            };
+            incident.sort_by(|a, b| angle(a).total_cmp(&angle(b)));
+        }
+
+        let corners: Vec<Vec<Vec3A>> = cell_triangles
+            .iter()
+            .map(|incident| incident.iter().map(|&triangle| triangle_centers[triangle]).collect())
+            .collect();
+
+        // Between two consecutive corners lies the neighbor shared by both of their triangles.
+        let neighbors: Vec<Vec<usize>> = cell_triangles
+            .iter()
+            .enumerate()
+            .map(|(cell, incident)| {
+                (0..incident.len())
+                    .map(|i| {
+                        let current = triangles[incident[i]];
+                        let next = triangles[incident[(i + 1) % incident.len()]];
+                        *current
+                            .iter()
+                            .find(|&&corner| corner != cell && next.contains(&corner))
+                            .expect("Consecutive triangles around a cell must share an edge")
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let mut edges = Vec::new();
+        let mut edge_indices = HashMap::new();
+        let mut cell_edges = vec![Vec::with_capacity(6); cell_count];
+
+        for (cell, cell_neighbors) in neighbors.iter().enumerate() {
+            for (i, &neighbor) in cell_neighbors.iter().enumerate() {
+                let key = (cell.min(neighbor), cell.max(neighbor));
+                let edge = *edge_indices.entry(key).or_insert_with(|| {
+                    let endpoints = [corners[cell][i], corners[cell][(i + 1) % cell_neighbors.len()]];
+                    edges.push(Edge {
+                        cells: [key.0, key.1],
+                        endpoints,
+                        length: arc_length(endpoints[0], endpoints[1]),
+                    });
+                    edges.len() - 1
+                });
+                cell_edges[cell].push(edge);
+            }
+        }
+
+        let mut centroids = Vec::with_capacity(cell_count);
+        let mut areas = Vec::with_capacity(cell_count);
+
+        // Each cell is a fan of spherical triangles from its center to each pair of consecutive corners.
+        for (cell, cell_corners) in corners.iter().enumerate() {
+            let center = points[cell];
+            let mut area = 0.0;
+            let mut weighted_sum = Vec3A::ZERO;
+
+            for i in 0..cell_corners.len() {
+                let a = cell_corners[i];
+                let b = cell_corners[(i + 1) % cell_corners.len()];
+                let fan_area = spherical_triangle_area(center, a, b);
+                area += fan_area;
+                weighted_sum += (center + a + b) * fan_area;
+            }
+
+            areas.push(area);
+            centroids.push(weighted_sum.normalize());
+        }
+
+        Self {
+            triangles,
+            neighbors,
+            corners,
+            cell_triangles,
+            edges,
+            cell_edges,
+            centroids,
+            areas,
+        }
+    }
+
+    pub fn cell_count(&self) -> usize {
+        self.neighbors.len()
+    }
+
+    /// Cells bordering the given cell, wound counter clockwise around it.
+    pub fn neighbors(&self, cell: usize) -> &[usize] {
+        &self.neighbors[cell]
+    }
+
+    /// Corners of the given cell on the unit sphere, wound counter clockwise.
+    pub fn corners(&self, cell: usize) -> &[Vec3A] {
+        &self.corners[cell]
+    }
+
+    /// Edges of the given cell, in the same order as its neighbors.
+    pub fn cell_edges(&self, cell: usize) -> impl Iterator<Item = &Edge> + '_ {
+        self.cell_edges[cell].iter().map(|&edge| &self.edges[edge])
+    }
+
+    /// The edge shared by two cells, if they are neighbors.
+    pub fn edge_between(&self, a: usize, b: usize) -> Option<&Edge> {
+        self.cell_edges(a).find(|edge| edge.other(a) == b)
+    }
+
+    /// Every edge of the globe, each listed once.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Center of mass of the given cell, projected onto the unit sphere.
+    pub fn centroid(&self, cell: usize) -> Vec3A {
+        self.centroids[cell]
+    }
+
+    /// Area of the given cell on the unit sphere, in steradians. The areas of all cells add up to 4π.
+    pub fn area(&self, cell: usize) -> f32 {
+        self.areas[cell]
+    }
+
+    /// Corner indices of every triangle of the icosphere, wound counter clockwise.
+    pub fn triangles(&self) -> &[[usize; 3]] {
+        &self.triangles
+    }
+
+    /// Indices into `triangles` of the triangles the given cell is a corner of, wound counter clockwise.
+    pub fn cell_triangles(&self, cell: usize) -> &[usize] {
+        &self.cell_triangles[cell]
+    }
+}
+
+/// Length of the shortest arc between two points on the unit sphere.
+pub fn arc_length(a: Vec3A, b: Vec3A) -> f32 {
+    a.cross(b).length().atan2(a.dot(b))
+}
+
+/// Area of the spherical triangle with the given corners on the unit sphere, from its spherical excess.
+fn spherical_triangle_area(a: Vec3A, b: Vec3A, c: Vec3A) -> f32 {
+    let numerator = a.dot(b.cross(c)).abs();
+    let denominator = 1.0 + a.dot(b) + b.dot(c) + c.dot(a);
+    2.0 * numerator.atan2(denominator)
+}
@@ -0,0 +1,325 @@
+use std::f32::consts::PI;
+
+use glam::Vec2;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use super::{
+    globe::{Globe, Surface},
+    position::{Meters, Position},
+    wind::{Wind, WindField},
+};
+
+/// Longest step in seconds the weather is advanced by at once, so that systems spawn, drift and die smoothly.
+const STEP: u64 = 60 * 60;
+/// Seconds in an hour, the unit rainfall rates are given in.
+const HOUR: f32 = 60.0 * 60.0;
+/// Seconds in a day, the unit spawn rates are given in.
+const DAY: f32 = 24.0 * HOUR;
+/// Hours in a year, to turn annual precipitation into an hourly rate.
+const HOURS_PER_YEAR: f32 = 365.25 * 24.0;
+
+/// Angle the wind spirals inwards towards the center of a low-pressure system, from friction with the surface.
+const INFLOW_ANGLE: f32 = 20.0 * PI / 180.0;
+/// Sea level temperature in °C a tropical cyclone needs to form.
+const CYCLONE_MIN_TEMPERATURE: f32 = 24.0;
+/// Fraction of its strength a tropical cyclone keeps for each hour it spends over land, cut off from the warm sea.
+const CYCLONE_LAND_DECAY: f32 = 0.9;
+/// Wind speeds in m/s at which the danger starts to rise from zero, at a strong breeze, and reaches its full
+/// measure at hurricane force.
+const DANGER_WIND_SPEEDS: (f32, f32) = (12.0, 33.0);
+
+/// Kinds of weather systems that move across the globe.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum WeatherKind {
+    /// A low-pressure system of the mid-latitudes, carried east by the westerlies with gales and rain.
+    Storm,
+    /// A tropical cyclone, born over warm seas, with violent winds and torrential rain around a calm eye.
+    Cyclone,
+    /// A stretch of still air in the doldrums or the horse latitudes, where ships lie becalmed.
+    Calm,
+}
+
+impl WeatherKind {
+    /// Mean number of systems of this kind born on the whole globe per day.
+    fn spawn_rate(&self) -> f32 {
+        match self {
+            WeatherKind::Storm => 1.5,
+            WeatherKind::Cyclone => 0.15,
+            WeatherKind::Calm => 0.6,
+        }
+    }
+
+    /// Band of latitudes in degrees, on either side of the equator, this kind of system is born in.
+    fn latitudes(&self) -> (f32, f32) {
+        match self {
+            WeatherKind::Storm => (35.0, 65.0),
+            WeatherKind::Cyclone => (8.0, 25.0),
+            WeatherKind::Calm => (0.0, 35.0),
+        }
+    }
+
+    /// Ranges of the radius in meters, the peak wind speed in m/s and the lifetime in days of this kind of system.
+    fn ranges(&self) -> ([Meters; 2], [f32; 2], [f32; 2]) {
+        match self {
+            WeatherKind::Storm => ([600e3, 1200e3], [15.0, 28.0], [3.0, 6.0]),
+            WeatherKind::Cyclone => ([300e3, 600e3], [35.0, 65.0], [5.0, 10.0]),
+            WeatherKind::Calm => ([400e3, 900e3], [0.0, 0.0], [2.0, 8.0]),
+        }
+    }
+
+    /// Distance from the center, as a fraction of the radius, where the winds are strongest.
+    fn core(&self) -> f32 {
+        match self {
+            WeatherKind::Storm => 0.4,
+            WeatherKind::Cyclone => 0.15,
+            WeatherKind::Calm => 1.0,
+        }
+    }
+
+    /// Rain in mm per hour under the strongest part of the system.
+    fn peak_precipitation(&self) -> f32 {
+        match self {
+            WeatherKind::Storm => 5.0,
+            WeatherKind::Cyclone => 20.0,
+            WeatherKind::Calm => 0.0,
+        }
+    }
+
+    /// How fast the system drifts along with the prevailing wind at its center, as a factor of its speed.
+    fn steering(&self) -> f32 {
+        match self {
+            WeatherKind::Storm => 1.3,
+            WeatherKind::Cyclone => 0.8,
+            WeatherKind::Calm => 0.3,
+        }
+    }
+
+    /// Whether this kind of system can be born over a cell.
+    fn can_form_over(&self, globe: &Globe, cell: usize) -> bool {
+        let cell = &globe.cells[cell];
+        match self {
+            WeatherKind::Storm | WeatherKind::Calm => cell.surface == Surface::Ocean,
+            WeatherKind::Cyclone => cell.surface == Surface::Ocean && cell.temperature >= CYCLONE_MIN_TEMPERATURE,
+        }
+    }
+}
+
+impl std::fmt::Display for WeatherKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            WeatherKind::Storm => "Storm",
+            WeatherKind::Cyclone => "Cyclone",
+            WeatherKind::Calm => "Calm",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A storm, cyclone or calm moving across the globe.
+#[derive(Clone, Debug)]
+pub struct WeatherSystem {
+    pub kind: WeatherKind,
+    pub center: Position,
+    /// Distance from the center in meters the system reaches out to.
+    pub radius: Meters,
+    /// Wind speed in m/s at the strongest part of the system, when at its peak.
+    pub strength: f32,
+    /// Seconds since the system was born.
+    pub age: u64,
+    /// Seconds the system lives for before it dies out.
+    pub lifetime: u64,
+}
+
+impl WeatherSystem {
+    /// How developed the system is, from zero when it is born, rising to one in the middle of its life
+    /// and falling back to zero as it dies out.
+    pub fn maturity(&self) -> f32 {
+        (PI * self.age as f32 / self.lifetime as f32).sin().max(0.0)
+    }
+
+    /// How strongly the system is felt at a distance from its center, from one at its strongest to zero at its edge.
+    fn influence(&self, distance: Meters) -> f32 {
+        let x = (distance / self.radius) as f32;
+        let core = self.kind.core();
+
+        let shape = if x >= 1.0 {
+            0.0
+        } else if self.kind == WeatherKind::Calm {
+            // Calms are felt evenly across their whole extent, rather than most strongly in a ring.
+            1.0
+        } else if x < core {
+            x / core
+        } else {
+            (1.0 - x) / (1.0 - core)
+        };
+        shape * self.maturity()
+    }
+
+    /// Wind the system adds at a position, spiralling inwards and around the center, counter-clockwise in
+    /// the northern hemisphere and clockwise in the southern.
+    fn wind_at(&self, position: &Position, radius: Meters) -> Vec2 {
+        let distance = self.center.distance_to(position, radius);
+        let influence = self.influence(distance);
+        if influence <= 0.0 || distance <= 0.0 {
+            return Vec2::ZERO;
+        }
+
+        let bearing = self.center.initial_bearing_to(position) as f32;
+        let outwards = Vec2::new(bearing.sin(), bearing.cos());
+        let turning = if self.center.latitude() >= 0.0 { 1.0 } else { -1.0 };
+        let around = outwards.perp() * turning;
+        let direction = around * INFLOW_ANGLE.cos() - outwards * INFLOW_ANGLE.sin();
+
+        direction * self.strength * influence
+    }
+}
+
+/// What the weather is like at some place and moment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Conditions {
+    pub wind: Wind,
+    /// Rain in mm per hour.
+    pub precipitation: f32,
+    /// How dangerous the weather is to travellers, from zero in fair weather to one in a hurricane.
+    pub danger: f32,
+    /// The weather system felt most strongly here, if any.
+    pub system: Option<WeatherKind>,
+}
+
+/// All the weather systems on the globe.
+#[derive(Clone)]
+pub struct Weather {
+    pub systems: Vec<WeatherSystem>,
+    rng: StdRng,
+}
+
+impl Weather {
+    /// Starts with clear skies everywhere. Systems are born as the weather is advanced.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            systems: Vec::new(),
+            rng: StdRng::seed_from_u64(seed),
+        }
+    }
+
+    /// Moves, grows and dissipates the existing systems, and gives birth to new ones, over a span of seconds.
+    pub fn advance(&mut self, globe: &Globe, wind: &WindField, seconds: u64) {
+        self.run(globe, wind, seconds, true);
+    }
+
+    /// The weather as it will be after a span of seconds, if no new systems are born in the meantime.
+    /// Only the systems already under way can be foreseen.
+    pub fn predict(&self, globe: &Globe, wind: &WindField, seconds: u64) -> Weather {
+        let mut future = self.clone();
+        future.run(globe, wind, seconds, false);
+        future
+    }
+
+    fn run(&mut self, globe: &Globe, wind: &WindField, seconds: u64, births: bool) {
+        let mut remaining = seconds;
+        while remaining > 0 {
+            let step = remaining.min(STEP);
+            self.step(globe, wind, step, births);
+            remaining -= step;
+        }
+    }
+
+    fn step(&mut self, globe: &Globe, wind: &WindField, seconds: u64, births: bool) {
+        let hours = seconds as f32 / HOUR;
+
+        for system in &mut self.systems {
+            let steering = wind.at(globe, &system.center).as_vec2() * system.kind.steering();
+            let mut drift = steering;
+            if system.kind == WeatherKind::Cyclone {
+                // Cyclones curve away from the equator as they go.
+                drift.y += 1.5 * system.center.latitude().signum() as f32;
+            }
+
+            let bearing = drift.x.atan2(drift.y) as f64;
+            let distance = (drift.length() * seconds as f32) as Meters;
+            system.center = system.center.destination(bearing, distance, globe.radius).normalized();
+            system.age += seconds;
+
+            if system.kind == WeatherKind::Cyclone && globe.cells[globe.cell_at(&system.center)].is_land() {
+                system.strength *= CYCLONE_LAND_DECAY.powf(hours);
+            }
+        }
+        self.systems.retain(|system| system.age < system.lifetime);
+        if !births {
+            return;
+        }
+
+        for kind in [WeatherKind::Storm, WeatherKind::Cyclone, WeatherKind::Calm] {
+            if self.rng.gen::<f32>() < kind.spawn_rate() * seconds as f32 / DAY {
+                self.spawn(globe, kind);
+            }
+        }
+    }
+
+    /// Gives birth to a system of the given kind at a random place in its band of latitudes,
+    /// if the surface there allows it.
+    fn spawn(&mut self, globe: &Globe, kind: WeatherKind) {
+        let (min_latitude, max_latitude) = kind.latitudes();
+        // Uniformly distributed over the area of the band
+        let sine = self.rng.gen_range(min_latitude.to_radians().sin()..max_latitude.to_radians().sin());
+        let hemisphere = if self.rng.gen() { 1.0 } else { -1.0 };
+        let latitude = sine.asin() * hemisphere;
+        let longitude = self.rng.gen_range(-PI..PI);
+        let center = Position::new(latitude as f64, longitude as f64);
+
+        if !kind.can_form_over(globe, globe.cell_at(&center)) {
+            return;
+        }
+
+        let ([min_radius, max_radius], [min_strength, max_strength], [min_days, max_days]) = kind.ranges();
+        self.systems.push(WeatherSystem {
+            kind,
+            center,
+            radius: self.rng.gen_range(min_radius..max_radius),
+            strength: if max_strength > min_strength {
+                self.rng.gen_range(min_strength..max_strength)
+            } else {
+                min_strength
+            },
+            age: 0,
+            lifetime: (self.rng.gen_range(min_days..max_days) * DAY) as u64,
+        });
+    }
+
+    /// The wind, rain and danger at a position, from the prevailing wind and climate modified by
+    /// the weather systems around it.
+    pub fn conditions_at(&self, globe: &Globe, wind: &WindField, position: &Position) -> Conditions {
+        let location = globe.locate(position);
+        let mut wind = wind.at(globe, position).as_vec2();
+        let mut precipitation = location.interpolate(|cell| globe.cells[cell].precipitation) / HOURS_PER_YEAR;
+        let mut strongest: Option<(WeatherKind, f32)> = None;
+
+        for system in &self.systems {
+            let influence = system.influence(position.distance_to(&system.center, globe.radius));
+            if influence <= 0.0 {
+                continue;
+            }
+
+            match system.kind {
+                WeatherKind::Calm => wind *= 1.0 - 0.9 * influence,
+                _ => {
+                    wind += system.wind_at(position, globe.radius);
+                    precipitation += system.kind.peak_precipitation() * influence;
+                }
+            }
+
+            if strongest.is_none_or(|(_, strongest)| influence > strongest) {
+                strongest = Some((system.kind, influence));
+            }
+        }
+
+        let speed = wind.length();
+        let (calm_speed, deadly_speed) = DANGER_WIND_SPEEDS;
+        Conditions {
+            wind: Wind::from_vec2(wind),
+            precipitation,
+            danger: ((speed - calm_speed) / (deadly_speed - calm_speed)).clamp(0.0, 1.0),
+            system: strongest.map(|(kind, _)| kind),
+        }
+    }
+}
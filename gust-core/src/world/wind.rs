@@ -0,0 +1,151 @@
+use std::f32::consts::{PI, TAU};
+
+use glam::Vec2;
+
+use super::{globe::Globe, position::Position};
+
+/// How far the wind belts follow the sun north in summer and south in winter, as a fraction of its declination.
+const SEASONAL_SHIFT: f32 = 0.35;
+
+/// Peak east-west speeds in m/s of the trade winds, the westerlies and the polar easterlies.
+const ZONAL_SPEEDS: [f32; 3] = [7.0, 9.0, 5.0];
+/// Peak north-south speeds in m/s of the same three belts.
+const MERIDIONAL_SPEEDS: [f32; 3] = [3.0, 2.5, 2.0];
+
+/// Factor the wind slows down by over land, from friction with the ground.
+const LAND_FRICTION: f32 = 0.6;
+/// Slope, in meters of rise per meter, at which terrain turns aside all of the wind blowing up it.
+const BLOCKING_SLOPE: f32 = 0.002;
+
+/// Velocity of the wind near the surface, in m/s.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Wind {
+    /// Speed towards the east. Negative when blowing towards the west.
+    pub east: f32,
+    /// Speed towards the north. Negative when blowing towards the south.
+    pub north: f32,
+}
+
+impl Wind {
+    pub fn new(east: f32, north: f32) -> Self {
+        Self { east, north }
+    }
+
+    pub fn speed(&self) -> f32 {
+        self.east.hypot(self.north)
+    }
+
+    /// Direction in radians, clockwise from north, that the wind blows from, as sailors name winds.
+    /// A northerly wind blowing from the north to the south has a direction of zero.
+    pub fn direction(&self) -> f32 {
+        (-self.east).atan2(-self.north).rem_euclid(TAU)
+    }
+
+    /// Constructs the wind blowing from a direction (in radians, clockwise from north) at a speed in m/s.
+    pub fn from_direction(direction: f32, speed: f32) -> Self {
+        Self {
+            east: -speed * direction.sin(),
+            north: -speed * direction.cos(),
+        }
+    }
+
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(self.east, self.north)
+    }
+
+    pub fn from_vec2(vector: Vec2) -> Self {
+        Self::new(vector.x, vector.y)
+    }
+}
+
+impl std::fmt::Display for Wind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const POINTS: [&str; 8] = ["N", "NE", "E", "SE", "S", "SW", "W", "NW"];
+        let point = ((self.direction() / (TAU / 8.0)).round() as usize) % 8;
+        write!(f, "{} {:.0} m/s", POINTS[point], self.speed())
+    }
+}
+
+/// The prevailing wind over each cell of a globe at some moment.
+pub struct WindField {
+    winds: Vec<Wind>,
+    /// Game time in seconds the field was computed for.
+    pub time_in_seconds: u64,
+}
+
+impl WindField {
+    pub fn compute(globe: &Globe, time_in_seconds: u64) -> Self {
+        let shift = seasonal_shift(globe, time_in_seconds);
+
+        Self {
+            winds: (0..globe.cells.len()).map(|cell| prevailing_wind(globe, cell, shift)).collect(),
+            time_in_seconds,
+        }
+    }
+
+    pub fn cell(&self, cell: usize) -> Wind {
+        self.winds[cell]
+    }
+
+    /// The wind at a position, blended from the cells around it.
+    pub fn at(&self, globe: &Globe, position: &Position) -> Wind {
+        let location = globe.locate(position);
+        Wind::new(location.interpolate(|cell| self.winds[cell].east), location.interpolate(|cell| self.winds[cell].north))
+    }
+}
+
+/// The prevailing wind at a position at any moment, without computing the whole field.
+pub fn prevailing_wind_at(globe: &Globe, position: &Position, time_in_seconds: u64) -> Wind {
+    let location = globe.locate(position);
+    let shift = seasonal_shift(globe, time_in_seconds);
+    let winds = location.triangle.map(|cell| prevailing_wind(globe, cell, shift));
+
+    Wind::from_vec2(winds.iter().zip(location.weights).map(|(wind, weight)| wind.as_vec2() * weight).sum())
+}
+
+/// Latitude in radians the wind belts are shifted by at a moment. They follow the sun, lagging an eighth of
+/// a year behind it as the seas warm up slowly, so the shift is northernmost in late northern summer.
+pub(crate) fn seasonal_shift(globe: &Globe, time_in_seconds: u64) -> f32 {
+    let year_length = globe.astronomy.year_length;
+    let lagged_time = time_in_seconds + year_length - year_length / 8;
+    SEASONAL_SHIFT * globe.astronomy.sun_declination(lagged_time) as f32
+}
+
+/// The prevailing wind over a cell, from the global circulation modulated by the terrain.
+fn prevailing_wind(globe: &Globe, cell: usize, shift: f32) -> Wind {
+    let latitude = globe.position_of(cell).latitude() as f32;
+    let mut wind = circulation(latitude - shift);
+
+    let cell_data = &globe.cells[cell];
+    if cell_data.is_land() {
+        wind *= LAND_FRICTION;
+
+        // Mountains turn aside the wind that would blow up their slopes.
+        let gradient = globe.elevation_gradient(cell);
+        let slope = gradient.length();
+        if slope > 0.0 {
+            let uphill = gradient / slope;
+            let climbing = wind.dot(uphill);
+            if climbing > 0.0 {
+                wind -= uphill * climbing * (slope / BLOCKING_SLOPE).min(1.0);
+            }
+        }
+    }
+
+    Wind::from_vec2(wind)
+}
+
+/// Wind of the three-cell global circulation at a latitude in radians: the trade winds blowing from the
+/// east towards the equator, the westerlies blowing from the west towards the poles, and the polar
+/// easterlies. The wind dies down at the borders between belts, in the doldrums and the horse latitudes.
+fn circulation(latitude: f32) -> Vec2 {
+    let latitude = latitude.clamp(-PI / 2.0, PI / 2.0);
+    let distance_from_equator = latitude.abs();
+    let belt = ((distance_from_equator / (PI / 6.0)) as usize).min(2);
+
+    // Positive in the middle of the trade winds and polar easterlies, negative in the westerlies
+    let strength = (6.0 * distance_from_equator).sin();
+    let towards_pole = latitude.signum();
+
+    Vec2::new(-strength * ZONAL_SPEEDS[belt], -strength * towards_pole * MERIDIONAL_SPEEDS[belt])
+}
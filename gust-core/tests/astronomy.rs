@@ -0,0 +1,61 @@
+use gust_core::world::astronomy::{Astronomy, Season, SunCycle};
+
+use common::{assert_close, at, HOUR};
+
+mod common;
+
+fn solstice(astronomy: &Astronomy) -> u64 {
+    astronomy.year_length / 4
+}
+
+#[test]
+fn days_and_nights_are_even_at_the_equinox() {
+    let astronomy = Astronomy::default();
+    assert_close(astronomy.sun_declination(0), 0.0, 1e-9);
+    for latitude in [-60.0, -30.0, 0.0, 30.0, 60.0] {
+        let daylight = astronomy.daylight_duration(0, &at(latitude, 0.0));
+        assert!(daylight.abs_diff(12 * HOUR) < HOUR / 2, "{} s of daylight at {latitude}°", daylight);
+    }
+}
+
+#[test]
+fn the_poles_have_midnight_sun_and_long_nights_at_the_solstice() {
+    let astronomy = Astronomy::default();
+    let june = solstice(&astronomy);
+    assert_close(astronomy.sun_declination(june), astronomy.axial_tilt, 1e-6);
+    assert_eq!(astronomy.sun_cycle(june, &at(80.0, 0.0)), SunCycle::PolarDay);
+    assert_eq!(astronomy.sun_cycle(june, &at(-80.0, 0.0)), SunCycle::PolarNight);
+    assert!(astronomy.daylight_duration(june, &at(45.0, 0.0)) > astronomy.daylight_duration(june, &at(-45.0, 0.0)));
+}
+
+#[test]
+fn the_sun_stands_overhead_at_the_subsolar_point() {
+    let astronomy = Astronomy::default();
+    for time in [0, 5 * HOUR, solstice(&astronomy) + 17 * HOUR] {
+        let elevation = astronomy.sun_elevation(time, &astronomy.subsolar_point(time));
+        assert_close(elevation, std::f64::consts::FRAC_PI_2, 0.01);
+        assert!(astronomy.is_daylight(time, &astronomy.subsolar_point(time)));
+    }
+}
+
+#[test]
+fn seasons_are_opposite_across_the_equator() {
+    let astronomy = Astronomy::default();
+    let june = solstice(&astronomy) + HOUR;
+    assert_eq!(astronomy.season(june, &at(45.0, 0.0)), Season::Summer);
+    assert_eq!(astronomy.season(june, &at(-45.0, 0.0)), Season::Winter);
+}
+
+#[test]
+fn sunshine_is_strongest_where_the_sun_is_high() {
+    let astronomy = Astronomy::default();
+    let june = solstice(&astronomy);
+    for time in [0, june] {
+        for degrees in (-90..=90).step_by(10) {
+            assert!(astronomy.daily_insolation(time, (degrees as f64).to_radians()) >= 0.0);
+        }
+    }
+    assert!(astronomy.daily_insolation(0, 0.0) > astronomy.daily_insolation(0, 60f64.to_radians()));
+    assert_eq!(astronomy.daily_insolation(june, -80f64.to_radians()), 0.0);
+    assert!(astronomy.daily_insolation(june, 40f64.to_radians()) > astronomy.daily_insolation(june, -40f64.to_radians()));
+}
@@ -0,0 +1,31 @@
+use std::collections::HashSet;
+
+use gust_core::world::{
+    biome::Biome,
+    globe::{GenerationParams, Globe, Surface},
+};
+
+#[test]
+fn biomes_are_deterministic() {
+    let params = GenerationParams::default();
+    let a = Globe::generate(9, &params);
+    let b = Globe::generate(9, &params);
+    assert!(a.cells.iter().zip(&b.cells).all(|(a, b)| a.biome == b.biome));
+}
+
+#[test]
+fn every_cell_has_the_biome_of_its_surface_and_climate() {
+    let globe = Globe::generate(9, &GenerationParams::default());
+    for cell in &globe.cells {
+        assert_eq!(cell.biome, Biome::classify(cell));
+        assert_eq!(cell.biome.is_water(), cell.surface != Surface::Land, "{} on {:?}", cell.biome, cell.surface);
+        assert_eq!(cell.biome.land_travel_cost().is_none(), cell.biome.is_water());
+    }
+}
+
+#[test]
+fn globe_has_many_biomes() {
+    let globe = Globe::generate(9, &GenerationParams::default());
+    let biomes: HashSet<Biome> = globe.cells.iter().map(|cell| cell.biome).collect();
+    assert!(biomes.len() >= 6, "only {biomes:?}");
+}
@@ -0,0 +1,62 @@
+use gust_core::world::{
+    climate,
+    globe::{GenerationParams, Globe, Surface},
+    wind::WindField,
+};
+
+use common::at;
+
+mod common;
+
+#[test]
+fn climate_is_deterministic() {
+    let params = GenerationParams::default();
+    let a = Globe::generate(6, &params);
+    let b = Globe::generate(6, &params);
+    for (a, b) in a.cells.iter().zip(&b.cells) {
+        assert_eq!((a.mean_temperature, a.annual_precipitation), (b.mean_temperature, b.annual_precipitation));
+        assert_eq!((a.temperature, a.precipitation, a.sea_ice), (b.temperature, b.precipitation, b.sea_ice));
+    }
+}
+
+#[test]
+fn the_tropics_are_warmer_than_the_poles() {
+    let globe = Globe::new();
+    let mean = |min: f64, max: f64| {
+        let cells: Vec<f32> = (0..globe.cell_count())
+            .filter(|&cell| (min..max).contains(&globe.position_of(cell).latitude().to_degrees().abs()))
+            .map(|cell| globe.cells[cell].mean_temperature)
+            .collect();
+        cells.iter().sum::<f32>() / cells.len() as f32
+    };
+    assert!(mean(0.0, 15.0) > mean(30.0, 45.0));
+    assert!(mean(30.0, 45.0) > mean(65.0, 90.0));
+    assert!(climate::sea_level_temperature(0.0) > climate::sea_level_temperature(1.0));
+}
+
+#[test]
+fn summer_is_warmer_than_winter() {
+    let mut globe = Globe::new();
+    let year = globe.astronomy.year_length;
+    let (north, south) = (at(50.0, 0.0), at(-50.0, 0.0));
+
+    globe.update_climate(&WindField::compute(&globe, year * 3 / 8));
+    let (north_summer, south_winter) = (globe.temperature_at(&north), globe.temperature_at(&south));
+    globe.update_climate(&WindField::compute(&globe, year * 7 / 8));
+    let (north_winter, south_summer) = (globe.temperature_at(&north), globe.temperature_at(&south));
+
+    assert!(north_summer > north_winter, "{north_summer} °C in summer, {north_winter} °C in winter");
+    assert!(south_summer > south_winter, "{south_summer} °C in summer, {south_winter} °C in winter");
+}
+
+#[test]
+fn only_cold_seas_freeze_over() {
+    let globe = Globe::generate(6, &GenerationParams::default());
+    for cell in &globe.cells {
+        assert!(cell.precipitation >= 0.0 && cell.annual_precipitation >= 0.0);
+        if cell.sea_ice {
+            assert_eq!(cell.surface, Surface::Ocean);
+            assert!(cell.temperature < 0.0);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+// Helpers shared by the integration tests, each of which uses only some of them
+#![allow(dead_code)]
+
+use gust_core::{
+    people::task::Task,
+    world::position::{Course, Position},
+    Game,
+};
+
+/// Seconds of game time in an hour.
+pub const HOUR: u64 = 60 * 60;
+
+/// The position at a latitude and longitude in degrees.
+pub fn at(latitude: f64, longitude: f64) -> Position {
+    Position::new(latitude.to_radians(), longitude.to_radians())
+}
+
+pub fn assert_close(actual: impl Into<f64>, expected: impl Into<f64>, tolerance: impl Into<f64>) {
+    let (actual, expected, tolerance) = (actual.into(), expected.into(), tolerance.into());
+    assert!((actual - expected).abs() <= tolerance, "expected {expected} ± {tolerance}, got {actual}");
+}
+
+/// A game with the player at the center of the first cell that fits, believing they are there.
+pub fn game_at(fits: impl Fn(&Game, usize) -> bool) -> (Game, usize) {
+    let mut game = Game::new(0, 9);
+    let cell = (0..game.globe.cells.len()).find(|&cell| fits(&game, cell)).expect("No cell fits");
+    let position = game.globe.position_of(cell);
+    game.player.position = position;
+    game.player.believed_position = position;
+    (game, cell)
+}
+
+/// Skips ahead to a time when the sun is up at the player's position for the next hour.
+pub fn wait_for_daylight(game: &mut Game) {
+    let position = game.player.position;
+    let astronomy = &game.globe.astronomy;
+    while !astronomy.is_daylight(game.time_in_seconds, &position) || !astronomy.is_daylight(game.time_in_seconds + HOUR, &position) {
+        game.time_in_seconds += HOUR;
+    }
+}
+
+/// Sets the player off along the great circle to a destination, without a route.
+pub fn head_for(game: &mut Game, destination: Position) {
+    game.player.task = Task::Traveling {
+        destination,
+        course: Course::GreatCircle,
+        route: None,
+    };
+}
@@ -0,0 +1,94 @@
+use std::f32::consts::{PI, TAU};
+
+use gust_core::{people::task::LISTENING_DURATION, world::forecast::Forecast, Game};
+use rand::{rngs::StdRng, SeedableRng};
+
+use common::{at, HOUR};
+
+mod common;
+
+/// Mean error in radians of the wind direction in the first entries of forecasts made at a spread of places.
+fn mean_direction_error(game: &Game, skill: f32) -> f32 {
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut total = 0.0;
+    let mut count = 0;
+    for latitude in (-60..=60).step_by(15) {
+        for longitude in (-180..180).step_by(30) {
+            let position = at(latitude as f64, longitude as f64);
+            let forecast = Forecast::listen(&game.globe, &game.wind, &game.weather, &position, game.time_in_seconds, skill, &mut rng);
+            let actual = game.weather.conditions_at(&game.globe, &game.wind, &position);
+            let difference = (forecast.entries[0].wind.direction() - actual.wind.direction()).rem_euclid(TAU);
+            total += difference.min(TAU - difference);
+            count += 1;
+        }
+    }
+    total / count as f32
+}
+
+#[test]
+fn skilled_listeners_see_further_ahead() {
+    let game = Game::new(0, 3);
+    let position = at(40.0, -30.0);
+    let mut rng = StdRng::seed_from_u64(1);
+    let mut horizon = |skill| Forecast::listen(&game.globe, &game.wind, &game.weather, &position, 0, skill, &mut rng).horizon();
+
+    assert!(horizon(0.25) >= 6 * HOUR);
+    assert!(horizon(5.0) > horizon(1.0));
+    assert!(horizon(10.0) > horizon(5.0));
+}
+
+#[test]
+fn skilled_listeners_are_more_accurate() {
+    let game = Game::new(0, 3);
+    let clumsy = mean_direction_error(&game, 0.25);
+    let skilled = mean_direction_error(&game, 8.0);
+
+    assert!(clumsy < PI / 2.0);
+    assert!(skilled < clumsy / 3.0, "skilled {skilled} against clumsy {clumsy}");
+}
+
+#[test]
+fn confidence_fades_with_lead_and_grows_with_skill() {
+    let game = Game::new(0, 3);
+    let position = at(10.0, 60.0);
+    let mut rng = StdRng::seed_from_u64(1);
+    let clumsy = Forecast::listen(&game.globe, &game.wind, &game.weather, &position, 0, 0.25, &mut rng);
+    let skilled = Forecast::listen(&game.globe, &game.wind, &game.weather, &position, 0, 8.0, &mut rng);
+
+    for entries in [&clumsy.entries, &skilled.entries] {
+        assert!(entries.windows(2).all(|pair| pair[1].confidence <= pair[0].confidence));
+    }
+    for (clumsy, skilled) in clumsy.entries.iter().zip(&skilled.entries) {
+        assert!(skilled.confidence > clumsy.confidence);
+    }
+}
+
+#[test]
+fn listening_makes_a_forecast_and_gives_experience() {
+    let mut game = Game::new(0, 3);
+    let skill = game.player.skillset.wind_listening.get();
+
+    game.start_listening();
+    game.advance_state(LISTENING_DURATION / 2);
+    assert!(game.player.forecast.is_none());
+
+    game.advance_state(LISTENING_DURATION / 2);
+    let forecast = game.player.forecast.as_ref().expect("a forecast once done listening");
+    assert_eq!(forecast.time_in_seconds, LISTENING_DURATION);
+    assert!(game.player.skillset.wind_listening.get() > skill);
+}
+
+#[test]
+fn forecasts_follow_from_the_world_seed() {
+    let listen = |seed| {
+        let mut game = Game::new(0, seed);
+        game.start_listening();
+        game.advance_state(LISTENING_DURATION);
+        let first = game.player.forecast.take().unwrap();
+        game.start_listening();
+        game.advance_state(LISTENING_DURATION);
+        (first.entries, game.player.forecast.unwrap().entries)
+    };
+
+    assert_eq!(listen(3), listen(3));
+}
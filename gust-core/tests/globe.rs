@@ -0,0 +1,33 @@
+use std::f32::consts::PI;
+
+use gust_core::world::globe::{GenerationParams, Globe};
+
+#[test]
+fn generation_is_deterministic() {
+    let params = GenerationParams::default();
+    let a = Globe::generate(7, &params);
+    let b = Globe::generate(7, &params);
+    let c = Globe::generate(8, &params);
+
+    let elevations = |globe: &Globe| globe.cells.iter().map(|cell| cell.elevation).collect::<Vec<_>>();
+    assert_eq!(elevations(&a), elevations(&b));
+    assert!(a.cells.iter().zip(&b.cells).all(|(a, b)| a.surface == b.surface));
+    assert_ne!(elevations(&a), elevations(&c));
+}
+
+#[test]
+fn cell_areas_cover_the_sphere() {
+    let globe = Globe::new();
+    let total: f32 = (0..globe.cell_count()).map(|cell| globe.topology.area(cell)).sum();
+    assert!((total - 4.0 * PI).abs() < 1e-3, "expected 4π, got {total}");
+}
+
+#[test]
+fn neighbors_are_mutual() {
+    let globe = Globe::new();
+    for cell in 0..globe.cell_count() {
+        for &neighbor in globe.topology.neighbors(cell) {
+            assert!(globe.topology.neighbors(neighbor).contains(&cell), "{cell} and {neighbor} disagree");
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use gust_core::world::{
+    globe::{GenerationParams, Globe, Surface},
+    hydrology::HydrologyParams,
+};
+
+#[test]
+fn rivers_are_deterministic() {
+    let params = GenerationParams::default();
+    let a = Globe::generate(5, &params);
+    let b = Globe::generate(5, &params);
+
+    for (a, b) in a.cells.iter().zip(&b.cells) {
+        assert_eq!((a.surface, a.river, a.downstream, a.flow), (b.surface, b.river, b.downstream, b.flow));
+    }
+    assert_eq!(a.river_cells().collect::<Vec<_>>(), b.river_cells().collect::<Vec<_>>());
+}
+
+#[test]
+fn all_water_on_land_drains_to_the_sea() {
+    let globe = Globe::generate(5, &GenerationParams::default());
+    for cell in 0..globe.cell_count() {
+        let path = globe.downstream_path(cell);
+        assert!(path.len() <= globe.cell_count(), "{cell} drains in circles");
+        assert_eq!(globe.cells[*path.last().unwrap()].surface, Surface::Ocean, "{cell} never reaches the sea");
+    }
+}
+
+#[test]
+fn flow_gathers_downstream() {
+    let globe = Globe::generate(5, &GenerationParams::default());
+    for cell in &globe.cells {
+        match cell.surface {
+            Surface::Ocean => assert!(cell.downstream.is_none() && !cell.river),
+            Surface::Land | Surface::Lake => {
+                assert!(cell.flow >= 1.0);
+                if let Some(next) = cell.downstream.filter(|&next| globe.cells[next].surface != Surface::Ocean) {
+                    assert!(globe.cells[next].flow > cell.flow);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn rivers_run_where_enough_water_gathers() {
+    let globe = Globe::generate(5, &GenerationParams::default());
+    let threshold = HydrologyParams::default().river_threshold;
+    assert!(globe.river_cells().count() > 0);
+    for cell in globe.river_cells() {
+        assert_eq!(globe.cells[cell].surface, Surface::Land);
+        assert!(globe.cells[cell].flow >= threshold);
+    }
+}
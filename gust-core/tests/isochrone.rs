@@ -0,0 +1,88 @@
+use std::collections::VecDeque;
+
+use gust_core::{
+    ships::polar::SpeedPolar,
+    world::{
+        currents::CurrentField,
+        globe::{GenerationParams, Globe, Surface},
+        position::Position,
+        wind::WindField,
+    },
+};
+
+/// Distance in meters between the points checked for land along each leg of a route, finer than the search itself looks.
+const CHECK_SPACING: f64 = 10.0 * 1000.0;
+/// Farthest apart in meters the ends of the routes tried are, so the voyages stay short.
+const NEARBY: f64 = 3000.0 * 1000.0;
+
+fn is_open_sea(globe: &Globe, cell: usize) -> bool {
+    globe.cells[cell].surface == Surface::Ocean && !globe.cells[cell].sea_ice
+}
+
+fn points_along(from: Position, to: Position, radius: f64) -> impl Iterator<Item = Position> {
+    let samples = (from.distance_to(&to, radius) / CHECK_SPACING).ceil().max(1.0) as usize;
+    (0..=samples).map(move |sample| from.interpolate(&to, sample as f64 / samples as f64))
+}
+
+/// The stretch of open sea each cell belongs to, numbered, or `None` for land, lakes and sea ice.
+fn seas(globe: &Globe) -> Vec<Option<usize>> {
+    let mut seas = vec![None; globe.cell_count()];
+    let mut count = 0;
+    for start in 0..globe.cell_count() {
+        if seas[start].is_some() || !is_open_sea(globe, start) {
+            continue;
+        }
+        seas[start] = Some(count);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cell) = queue.pop_front() {
+            for &neighbor in globe.topology.neighbors(cell) {
+                if seas[neighbor].is_none() && is_open_sea(globe, neighbor) {
+                    seas[neighbor] = Some(count);
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+        count += 1;
+    }
+    seas
+}
+
+/// Nearby pairs of cells on the same stretch of open sea with land on the great circle between them, so that
+/// the way between them has to go around it.
+fn pairs_across_land(globe: &Globe) -> Vec<(Position, Position)> {
+    let seas = seas(globe);
+    let sea: Vec<usize> = (0..globe.cell_count()).filter(|&cell| seas[cell].is_some()).collect();
+    sea.iter()
+        .step_by(37)
+        .flat_map(|&a| sea.iter().step_by(5).map(move |&b| (a, b)))
+        .filter(|&(a, b)| seas[a] == seas[b])
+        .map(|(a, b)| (globe.position_of(a), globe.position_of(b)))
+        .filter(|(a, b)| a.distance_to(b, globe.radius) < NEARBY)
+        .filter(|(a, b)| points_along(*a, *b, globe.radius).any(|point| globe.cells[globe.cell_at(&point)].is_land()))
+        .take(4)
+        .collect()
+}
+
+#[test]
+fn sea_routes_sail_around_land() {
+    for seed in [1, 5, 9, 13] {
+        let globe = Globe::generate(seed, &GenerationParams::default());
+        let wind = WindField::compute(&globe, 0);
+        let currents = CurrentField::compute(&globe, &wind);
+        let pairs = pairs_across_land(&globe);
+        assert!(!pairs.is_empty(), "seed {seed} has no land between nearby seas");
+
+        for (from, to) in pairs {
+            let route = globe
+                .find_sea_route(&from, &to, 0, &currents, &SpeedPolar::default())
+                .unwrap_or_else(|| panic!("seed {seed}: no way found from {from} to {to}"));
+            assert!(route.cells.iter().all(|&cell| is_open_sea(&globe, cell)), "seed {seed}: {from} to {to} passes over land");
+            for leg in route.waypoints.windows(2) {
+                for point in points_along(leg[0], leg[1], globe.radius) {
+                    assert!(!globe.cells[globe.cell_at(&point)].is_land(), "seed {seed}: {from} to {to} sails over land at {point}");
+                }
+            }
+            assert!(route.distance > from.distance_to(&to, globe.radius));
+        }
+    }
+}
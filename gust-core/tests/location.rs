@@ -0,0 +1,69 @@
+use gust_core::world::{globe::Globe, position::Position};
+
+use common::{assert_close, at};
+
+mod common;
+
+fn sample_positions() -> impl Iterator<Item = Position> {
+    (-89..=89)
+        .step_by(7)
+        .flat_map(|latitude| (-180..180).step_by(11).map(move |longitude| at(latitude as f64, longitude as f64)))
+}
+
+#[test]
+fn locate_finds_the_nearest_cell() {
+    let globe = Globe::new();
+    for position in sample_positions() {
+        let location = globe.locate(&position);
+        let closeness = |cell: usize| position.to_unit_vector().dot(globe.position_of(cell).to_unit_vector());
+        let nearest = (0..globe.cell_count()).max_by(|a, b| closeness(*a).total_cmp(&closeness(*b))).unwrap();
+        assert!(
+            closeness(location.cell) >= closeness(nearest) - 1e-6,
+            "{position:?} located in {} rather than {nearest}",
+            location.cell
+        );
+    }
+}
+
+#[test]
+fn locate_weights_add_up_to_one() {
+    let globe = Globe::new();
+    for position in sample_positions() {
+        let location = globe.locate(&position);
+        assert!(
+            location.weights.iter().all(|&weight| weight >= -1e-5),
+            "negative weight at {position:?}: {:?}",
+            location.weights
+        );
+        assert_close(location.weights.iter().sum::<f32>(), 1.0, 1e-4);
+    }
+}
+
+#[test]
+fn cell_centers_locate_to_themselves() {
+    let globe = Globe::new();
+    for cell in 0..globe.cell_count() {
+        let location = globe.locate(&globe.position_of(cell));
+        assert_eq!(location.cell, cell);
+        assert_close(location.interpolate(|corner| if corner == cell { 1.0 } else { 0.0 }), 1.0, 1e-3);
+    }
+}
+
+#[test]
+fn cells_along_a_great_circle_are_every_cell_it_crosses() {
+    let globe = Globe::new();
+    let positions: Vec<Position> = sample_positions().step_by(13).collect();
+    for pair in positions.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        let cells = globe.cells_along(&from, &to);
+        assert_eq!((cells[0], *cells.last().unwrap()), (globe.cell_at(&from), globe.cell_at(&to)));
+        assert!(
+            cells.windows(2).all(|step| globe.topology.neighbors(step[0]).contains(&step[1])),
+            "{from} to {to} skips a cell"
+        );
+
+        let mut sampled: Vec<usize> = (0..=2000).map(|sample| globe.cell_at(&from.interpolate(&to, sample as f64 / 2000.0))).collect();
+        sampled.dedup();
+        assert!(sampled.iter().all(|cell| cells.contains(cell)), "{from} to {to} misses a cell");
+    }
+}
@@ -0,0 +1,110 @@
+use std::f64::consts::{PI, TAU};
+
+use gust_core::{
+    people::{
+        navigation,
+        skills::{Skill, MAX_SKILL},
+        task::Task,
+    },
+    world::{
+        magnetism::{Anomaly, MagneticField},
+        position::Course,
+    },
+};
+
+use common::{assert_close, at, game_at, wait_for_daylight, HOUR};
+
+mod common;
+
+/// A dipole whose north pole lies 10° off the true one, on the prime meridian.
+fn tilted() -> MagneticField {
+    MagneticField::dipole(at(80.0, 0.0), 30.0)
+}
+
+#[test]
+fn no_declination_along_the_dipole_meridian() {
+    let field = tilted();
+    for latitude in [-60.0, -20.0, 0.0, 30.0, 60.0] {
+        assert_close(field.declination(&at(latitude, 0.0)), 0.0, 1e-5);
+    }
+    // Beyond the true pole, the way to the magnetic pole leads over it.
+    for latitude in [20.0, 50.0] {
+        assert_close(field.declination(&at(latitude, 180.0)), 0.0, 1e-5);
+    }
+}
+
+#[test]
+fn no_declination_with_the_dipole_on_the_axis() {
+    let field = MagneticField::dipole(at(90.0, 0.0), 30.0);
+    for (latitude, longitude) in [(0.0, 45.0), (50.0, -120.0), (-70.0, 170.0)] {
+        assert_close(field.declination(&at(latitude, longitude)), 0.0, 1e-5);
+    }
+}
+
+#[test]
+fn declination_points_towards_the_magnetic_pole() {
+    let field = tilted();
+    // West of the magnetic pole, needles point east of true north, and east of it to the west.
+    assert!(field.declination(&at(40.0, -30.0)) > 0.0);
+    assert!(field.declination(&at(40.0, 30.0)) < 0.0);
+    assert_close(field.declination(&at(40.0, -30.0)), -field.declination(&at(40.0, 30.0)), 1e-5);
+}
+
+#[test]
+fn headings_convert_back_and_forth() {
+    let field = tilted();
+    let position = at(40.0, -30.0);
+    let compass = field.compass_heading(1.0, &position);
+    assert_close(compass, 1.0 - field.declination(&position), 1e-9);
+    assert_close(field.true_heading(compass, &position), 1.0, 1e-9);
+}
+
+#[test]
+fn anomaly_pulls_needles_towards_it() {
+    let mut field = MagneticField::dipole(at(90.0, 0.0), 30.0);
+    field.anomalies.push(Anomaly {
+        position: at(10.0, 5.0),
+        strength: 20.0,
+        radius: 5f64.to_radians(),
+    });
+    assert!(field.declination(&at(10.0, 2.0)) > 0.0);
+    assert!(field.declination(&at(10.0, 8.0)) < 0.0);
+}
+
+#[test]
+fn skill_allows_for_more_of_the_declination() {
+    assert!(navigation::declination_correction(MAX_SKILL) > navigation::declination_correction(1.0));
+    assert_eq!(navigation::declination_correction(MAX_SKILL), 1.0);
+}
+
+#[test]
+fn compass_turns_travelers_by_the_declination_where_they_are() {
+    // Well inland off the dipole meridian, believing they are on it where the compass points true north
+    let (mut game, _) = game_at(|game, cell| {
+        let globe = &game.globe;
+        let position = globe.position_of(cell);
+        globe.cells[cell].is_land()
+            && globe.topology.neighbors(cell).iter().all(|&n| globe.cells[n].is_land())
+            && tilted().declination(&position).abs() > 0.1
+            && position.latitude().abs() < 1.0
+    });
+    game.magnetism = tilted();
+    let position = game.player.position;
+    game.player.believed_position = at(position.latitude().to_degrees(), 0.0);
+    game.player.skillset.traveling = Skill::new(1.0, 1e6);
+    game.player.skillset.navigation = Skill::new(1.0, 1e6);
+    wait_for_daylight(&mut game);
+
+    // Making due north as they believe
+    let destination = game.player.believed_position.destination(0.0, 100_000.0, game.globe.radius);
+    game.player.task = Task::Traveling {
+        destination,
+        course: Course::GreatCircle,
+        route: None,
+    };
+    game.advance_state(HOUR);
+
+    let bearing = position.initial_bearing_to(&game.player.position);
+    let bearing = (bearing + PI).rem_euclid(TAU) - PI;
+    assert_close(bearing, tilted().declination(&position), 0.05);
+}
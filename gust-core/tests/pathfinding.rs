@@ -0,0 +1,76 @@
+use std::{cmp::Reverse, collections::BinaryHeap};
+
+use gust_core::world::globe::{GenerationParams, Globe};
+
+/// Cheapest cost on foot from one cell to every other, by Dijkstra's search over all the cells.
+fn cheapest_costs(globe: &Globe, start: usize) -> Vec<f32> {
+    let mut costs = vec![f32::INFINITY; globe.cells.len()];
+    let mut frontier = BinaryHeap::new();
+    costs[start] = 0.0;
+    frontier.push((Reverse(0), start));
+
+    while let Some((_, cell)) = frontier.pop() {
+        for &neighbor in globe.topology.neighbors(cell) {
+            let Some(step) = globe.land_step_cost(cell, neighbor) else {
+                continue;
+            };
+            let cost = costs[cell] + step;
+            if cost < costs[neighbor] {
+                costs[neighbor] = cost;
+                // Non-negative floats order the same as their bits.
+                frontier.push((Reverse(cost.to_bits()), neighbor));
+            }
+        }
+    }
+    costs
+}
+
+/// Pairs of land cells joined over land, spread over the globe.
+fn land_pairs(globe: &Globe) -> Vec<(usize, usize, f32)> {
+    let land: Vec<usize> = (0..globe.cells.len()).filter(|&cell| globe.cells[cell].biome.land_travel_cost().is_some()).collect();
+    land.iter()
+        .step_by(17)
+        .flat_map(|&start| {
+            let costs = cheapest_costs(globe, start);
+            land.iter().step_by(23).filter_map(move |&goal| {
+                if goal != start && costs[goal].is_finite() {
+                    Some((start, goal, costs[goal]))
+                } else {
+                    None
+                }
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn land_routes_are_cheapest() {
+    let globe = Globe::generate(11, &GenerationParams::default());
+    let pairs = land_pairs(&globe);
+    assert!(!pairs.is_empty());
+
+    for (start, goal, cheapest) in pairs {
+        let route = globe.find_land_route(&globe.position_of(start), &globe.position_of(goal), 1.0).expect("No route over land");
+        let cost: f32 = route.cells.windows(2).map(|step| globe.land_step_cost(step[0], step[1]).unwrap()).sum();
+        assert!((cost - cheapest).abs() <= cheapest * 1e-4 + 1.0, "{start} to {goal} costs {cost} rather than {cheapest}");
+    }
+}
+
+#[test]
+fn land_routes_stay_on_land() {
+    let globe = Globe::generate(11, &GenerationParams::default());
+    for (start, goal, _) in land_pairs(&globe) {
+        let route = globe.find_land_route(&globe.position_of(start), &globe.position_of(goal), 1.0).unwrap();
+        assert_eq!((route.cells[0], *route.cells.last().unwrap()), (start, goal));
+        assert!(route.cells.windows(2).all(|step| globe.topology.neighbors(step[0]).contains(&step[1])));
+        assert!(route.cells.iter().all(|&cell| globe.cells[cell].is_land()), "{start} to {goal} crosses water");
+    }
+}
+
+#[test]
+fn no_land_route_to_water() {
+    let globe = Globe::generate(11, &GenerationParams::default());
+    let land = (0..globe.cells.len()).find(|&cell| globe.cells[cell].is_land()).unwrap();
+    let water = (0..globe.cells.len()).find(|&cell| !globe.cells[cell].is_land()).unwrap();
+    assert!(globe.find_land_route(&globe.position_of(land), &globe.position_of(water), 1.0).is_none());
+}
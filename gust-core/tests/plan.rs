@@ -0,0 +1,66 @@
+use gust_core::people::plan::{Plan, PlannedTask};
+
+fn rest(hours: u64) -> PlannedTask {
+    PlannedTask::Rest { duration: hours * 60 * 60 }
+}
+
+fn trade(hours: u64) -> PlannedTask {
+    PlannedTask::Trade { duration: hours * 60 * 60 }
+}
+
+/// A plan of resting for 1, 2 and 3 hours, in that order.
+fn plan() -> Plan {
+    let mut plan = Plan::new();
+    for hours in 1..=3 {
+        plan.push(rest(hours));
+    }
+    plan
+}
+
+fn tasks(plan: &Plan) -> Vec<PlannedTask> {
+    plan.iter().copied().collect()
+}
+
+#[test]
+fn insert_goes_before_the_task_at_its_place() {
+    let mut plan = plan();
+    plan.insert(0, PlannedTask::Listen);
+    plan.insert(2, trade(1));
+    assert_eq!(tasks(&plan), [PlannedTask::Listen, rest(1), trade(1), rest(2), rest(3)]);
+}
+
+#[test]
+fn insert_past_the_end_appends() {
+    let mut plan = plan();
+    plan.insert(3, PlannedTask::Listen);
+    plan.insert(100, trade(1));
+    assert_eq!(tasks(&plan), [rest(1), rest(2), rest(3), PlannedTask::Listen, trade(1)]);
+}
+
+#[test]
+fn reorder_shifts_the_tasks_between() {
+    let mut plan = plan();
+    assert!(plan.reorder(0, 2));
+    assert_eq!(tasks(&plan), [rest(2), rest(3), rest(1)]);
+    assert!(plan.reorder(2, 0));
+    assert_eq!(tasks(&plan), [rest(1), rest(2), rest(3)]);
+    assert!(plan.reorder(1, 100));
+    assert_eq!(tasks(&plan), [rest(1), rest(3), rest(2)]);
+}
+
+#[test]
+fn reorder_from_past_the_end_does_nothing() {
+    let mut plan = plan();
+    assert!(!plan.reorder(3, 0));
+    assert_eq!(tasks(&plan), [rest(1), rest(2), rest(3)]);
+}
+
+#[test]
+fn cancel_takes_out_the_task_at_its_place() {
+    let mut plan = plan();
+    assert_eq!(plan.cancel(1), Some(rest(2)));
+    assert_eq!(plan.cancel(5), None);
+    assert_eq!(tasks(&plan), [rest(1), rest(3)]);
+    assert_eq!(plan.take_next(), Some(rest(1)));
+    assert_eq!(plan.len(), 1);
+}
@@ -0,0 +1,227 @@
+use gust_core::world::position::{Meters, Position, EARTH_RADIUS};
+
+use common::{assert_close, at};
+
+mod common;
+
+const KM: Meters = 1000.0;
+
+const LONDON: (f64, f64) = (51.5074, -0.1278);
+const PARIS: (f64, f64) = (48.8566, 2.3522);
+const NEW_YORK: (f64, f64) = (40.7128, -74.0060);
+const LOS_ANGELES: (f64, f64) = (34.0522, -118.2437);
+const SYDNEY: (f64, f64) = (-33.8688, 151.2093);
+const TOKYO: (f64, f64) = (35.6762, 139.6503);
+
+#[test]
+fn city_pair_distances() {
+    let pairs = [
+        (LONDON, PARIS, 344.0 * KM),
+        (NEW_YORK, LOS_ANGELES, 3936.0 * KM),
+        (NEW_YORK, LONDON, 5570.0 * KM),
+        (SYDNEY, TOKYO, 7826.0 * KM),
+    ];
+
+    for (a, b, expected) in pairs {
+        let (a, b) = (at(a.0, a.1), at(b.0, b.1));
+        assert_close(a.distance_to(&b, EARTH_RADIUS), expected, 1.0 * KM);
+        assert_close(b.distance_to(&a, EARTH_RADIUS), expected, 1.0 * KM);
+    }
+}
+
+#[test]
+fn distance_scales_with_radius() {
+    let (a, b) = (at(LONDON.0, LONDON.1), at(PARIS.0, PARIS.1));
+    assert_close(a.distance_to(&b, EARTH_RADIUS / 2.0), a.distance_to(&b, EARTH_RADIUS) / 2.0, 1e-6);
+}
+
+#[test]
+fn bearings() {
+    let baghdad = at(35.0, 45.0);
+    let osaka = at(35.0, 135.0);
+    assert_close(baghdad.initial_bearing_to(&osaka).to_degrees(), 60.16, 0.01);
+    assert_close(baghdad.final_bearing_to(&osaka).to_degrees(), 119.84, 0.01);
+
+    let london = at(LONDON.0, LONDON.1);
+    let paris = at(PARIS.0, PARIS.1);
+    assert_close(london.initial_bearing_to(&paris).to_degrees(), 148.1, 0.1);
+
+    let equator = at(0.0, 0.0);
+    assert_close(equator.initial_bearing_to(&at(10.0, 0.0)).to_degrees(), 0.0, 1e-9);
+    assert_close(equator.initial_bearing_to(&at(0.0, 10.0)).to_degrees(), 90.0, 1e-9);
+    assert_close(equator.initial_bearing_to(&at(-10.0, 0.0)).to_degrees(), 180.0, 1e-9);
+    assert_close(equator.initial_bearing_to(&at(0.0, -10.0)).to_degrees(), 270.0, 1e-9);
+}
+
+#[test]
+fn destination_inverts_distance_and_bearing() {
+    let new_york = at(NEW_YORK.0, NEW_YORK.1);
+    let london = at(LONDON.0, LONDON.1);
+
+    let reached = new_york.destination(new_york.initial_bearing_to(&london), new_york.distance_to(&london, EARTH_RADIUS), EARTH_RADIUS);
+    assert_close(reached.distance_to(&london, EARTH_RADIUS), 0.0, 1.0);
+}
+
+#[test]
+fn destination_wraps_across_antimeridian() {
+    let reached = at(0.0, 179.0).destination(90.0_f64.to_radians(), 2.0_f64.to_radians() * EARTH_RADIUS, EARTH_RADIUS);
+    assert_close(reached.longitude().to_degrees(), -179.0, 1e-6);
+    assert_close(reached.latitude().to_degrees(), 0.0, 1e-6);
+}
+
+#[test]
+fn interpolation_stays_on_great_circle() {
+    let sydney = at(SYDNEY.0, SYDNEY.1);
+    let tokyo = at(TOKYO.0, TOKYO.1);
+    let total = sydney.distance_to(&tokyo, EARTH_RADIUS);
+
+    let midpoint = sydney.interpolate(&tokyo, 0.5);
+    assert_close(sydney.distance_to(&midpoint, EARTH_RADIUS), total / 2.0, 1.0);
+    assert_close(midpoint.distance_to(&tokyo, EARTH_RADIUS), total / 2.0, 1.0);
+}
+
+#[test]
+fn moving_towards_destination() {
+    let paris = at(PARIS.0, PARIS.1);
+    let mut position = at(LONDON.0, LONDON.1);
+    let total = position.distance_to(&paris, EARTH_RADIUS);
+
+    let left_over = position.r#move(100.0 * KM, &paris, EARTH_RADIUS);
+    assert_eq!(left_over, 0.0);
+    assert_close(position.distance_to(&paris, EARTH_RADIUS), total - 100.0 * KM, 1.0);
+
+    let left_over = position.r#move(1000.0 * KM, &paris, EARTH_RADIUS);
+    assert_eq!(position, paris);
+    assert_close(left_over, 1000.0 * KM - (total - 100.0 * KM), 1.0);
+}
+
+#[test]
+fn moving_between_antipodes() {
+    let destination = at(0.0, 180.0);
+    let mut position = at(0.0, 0.0);
+
+    position.r#move(EARTH_RADIUS, &destination, EARTH_RADIUS);
+    assert_close(position.distance_to(&destination, EARTH_RADIUS), (std::f64::consts::PI - 1.0) * EARTH_RADIUS, 1.0);
+}
+
+#[test]
+fn rhumb_line_distance_and_bearing() {
+    // Along the equator and meridians, rhumb lines and great circles coincide.
+    let origin = at(0.0, 0.0);
+    let east = at(0.0, 10.0);
+    assert_close(origin.rhumb_distance_to(&east, EARTH_RADIUS), origin.distance_to(&east, EARTH_RADIUS), 1e-3);
+    assert_close(origin.rhumb_bearing_to(&east).to_degrees(), 90.0, 1e-9);
+
+    // Elsewhere the rhumb line is longer.
+    let new_york = at(NEW_YORK.0, NEW_YORK.1);
+    let london = at(LONDON.0, LONDON.1);
+    let rhumb = new_york.rhumb_distance_to(&london, EARTH_RADIUS);
+    assert!(rhumb > new_york.distance_to(&london, EARTH_RADIUS));
+    assert_close(rhumb, 5794.0 * KM, 5.0 * KM);
+    assert_close(new_york.rhumb_bearing_to(&london).to_degrees(), 78.0, 0.1);
+}
+
+#[test]
+fn rhumb_line_follows_parallel_when_heading_east() {
+    let start = at(60.0, 0.0);
+    let reached = start.rhumb_destination(90.0_f64.to_radians(), 1000.0 * KM, EARTH_RADIUS);
+    assert_close(reached.latitude().to_degrees(), 60.0, 1e-9);
+    // At 60° the parallel is half as long as the equator, so the longitude changes twice as fast.
+    assert_close(reached.longitude(), 2.0 * 1000.0 * KM / EARTH_RADIUS, 1e-9);
+}
+
+#[test]
+fn rhumb_line_crosses_antimeridian_the_short_way() {
+    let a = at(10.0, 179.0);
+    let b = at(12.0, -179.0);
+    assert!(a.rhumb_distance_to(&b, EARTH_RADIUS) < 400.0 * KM);
+    assert!(a.rhumb_bearing_to(&b).to_degrees() < 90.0);
+}
+
+#[test]
+fn rhumb_move_reaches_destination() {
+    let new_york = at(NEW_YORK.0, NEW_YORK.1);
+    let london = at(LONDON.0, LONDON.1);
+    let total = new_york.rhumb_distance_to(&london, EARTH_RADIUS);
+
+    let mut position = new_york;
+    let left_over = position.rhumb_move(total / 3.0, &london, EARTH_RADIUS);
+    assert_eq!(left_over, 0.0);
+    assert_close(position.rhumb_distance_to(&london, EARTH_RADIUS), total * 2.0 / 3.0, 10.0);
+    assert_close(position.rhumb_bearing_to(&london), new_york.rhumb_bearing_to(&london), 1e-6);
+
+    position.rhumb_move(total, &london, EARTH_RADIUS);
+    assert_eq!(position, london);
+}
+
+#[test]
+fn parsing_notations() {
+    let expected = at(51.5074, -0.1278);
+    let texts = [
+        "51.5074, -0.1278",
+        "51.5074 -0.1278",
+        "51.5074°N, 0.1278°W",
+        "51.5074 n 0.1278 w",
+        "N 51.5074 W 0.1278",
+        "51°30.444'N 0°07.668'W",
+        "51°30'26.64\"N, 0°07'40.08\"W",
+        "51 30 26.64 N 0 7 40.08 W",
+    ];
+
+    for text in texts {
+        let parsed: Position = text.parse().unwrap_or_else(|error| panic!("{text}: {error}"));
+        assert_close(parsed.distance_to(&expected, EARTH_RADIUS), 0.0, 1.0);
+    }
+}
+
+#[test]
+fn parsing_rejects_invalid_coordinates() {
+    let texts = ["", "51.5", "91 N 0 E", "51 E 0 N", "51 61 N 0 W", "51 N, 0 W W", "fifty N, 0 W", "1 2 3 4 N 0 W"];
+
+    for text in texts {
+        assert!(text.parse::<Position>().is_err(), "{text} should not parse");
+    }
+}
+
+#[test]
+fn parsing_wraps_longitude() {
+    let parsed: Position = "10, 190".parse().unwrap();
+    assert_close(parsed.longitude().to_degrees(), -170.0, 1e-9);
+}
+
+#[test]
+fn formatting_notations() {
+    use gust_core::world::position::notation::CoordinateFormat;
+
+    let london = at(51.5074, -0.1278);
+    assert_eq!(london.to_string(), "51.507°, -0.128°");
+    assert_eq!(london.format(CoordinateFormat::DecimalHemisphere).to_string(), "51.507°N, 0.128°W");
+    assert_eq!(london.format(CoordinateFormat::DegreesMinutes).to_string(), "51°30.4'N, 0°07.7'W");
+    assert_eq!(london.format(CoordinateFormat::DegreesMinutesSeconds).to_string(), "51°30'27\"N, 0°07'40\"W");
+    assert_eq!(format!("{:.1}", london.format(CoordinateFormat::DegreesMinutesSeconds)), "51°30'26.6\"N, 0°07'40.1\"W");
+
+    // Rounding carries over into the minutes and degrees rather than showing 60 seconds.
+    let almost = at(10.0 - 0.1 / 3600.0, 20.0);
+    assert_eq!(almost.format(CoordinateFormat::DegreesMinutesSeconds).to_string(), "10°00'00\"N, 20°00'00\"E");
+
+    // Longitudes are wrapped, and latitudes past a pole come back down the other side.
+    assert_eq!(at(0.0, 200.0).to_string(), "0.000°, -160.000°");
+    assert_eq!(at(100.0, 0.0).to_string(), "80.000°, -180.000°");
+}
+
+#[test]
+fn formatting_round_trips_through_parsing() {
+    use gust_core::world::position::notation::CoordinateFormat;
+
+    let sydney = at(SYDNEY.0, SYDNEY.1);
+    for format in [
+        CoordinateFormat::Decimal,
+        CoordinateFormat::DecimalHemisphere,
+        CoordinateFormat::DegreesMinutes,
+        CoordinateFormat::DegreesMinutesSeconds,
+    ] {
+        let text = format!("{:.4}", sydney.format(format));
+        let parsed: Position = text.parse().unwrap_or_else(|error| panic!("{text}: {error}"));
+        assert_close(parsed.distance_to(&sydney, EARTH_RADIUS), 0.0, 20.0);
+    }
+}
@@ -0,0 +1,182 @@
+use gust_core::{
+    event::EventKind,
+    people::{
+        navigation::{self, Observation, SIGHTING_RANGE},
+        skills::MAX_SKILL,
+        task::Task,
+    },
+    world::{globe::Surface, position::Meters},
+    Game,
+};
+
+use common::{game_at, head_for, wait_for_daylight, HOUR};
+
+mod common;
+
+const KM: Meters = 1000.0;
+
+#[test]
+fn landmark_fix_sets_belief_right() {
+    let (mut game, _) = game_at(|_, _| true);
+    let radius = game.globe.radius;
+    game.player.believed_position = game.player.position.destination(1.0, 200.0 * KM, radius);
+    game.player.observe(Observation::Landmark, radius);
+    assert_eq!(game.player.position_error(radius), 0.0);
+}
+
+#[test]
+fn coastline_brings_belief_within_sight() {
+    let (mut game, _) = game_at(|_, _| true);
+    let radius = game.globe.radius;
+    game.player.believed_position = game.player.position.destination(1.0, 200.0 * KM, radius);
+    game.player.observe(Observation::Coastline, radius);
+    assert!((game.player.position_error(radius) - SIGHTING_RANGE).abs() < 1.0);
+
+    // Belief already within sight of the truth is left alone.
+    let believed = game.player.position.destination(2.0, 10.0 * KM, radius);
+    game.player.believed_position = believed;
+    game.player.observe(Observation::Coastline, radius);
+    assert_eq!(game.player.believed_position, believed);
+}
+
+#[test]
+fn skill_steadies_the_heading() {
+    assert!(navigation::steering_error(MAX_SKILL) < navigation::steering_error(1.0));
+}
+
+#[test]
+fn only_the_skilled_know_the_land_without_landmarks() {
+    assert_eq!(navigation::landmark_chance(0.0), 0.0);
+    assert!(navigation::landmark_chance(1.0) < navigation::landmark_chance(MAX_SKILL));
+    assert!(navigation::landmark_chance(MAX_SKILL) < 1.0);
+}
+
+#[test]
+fn open_sea_reckoning_drifts() {
+    let (mut game, _) = game_at(|game, cell| {
+        let globe = &game.globe;
+        globe.cells[cell].surface == Surface::Ocean && globe.topology.neighbors(cell).iter().all(|&n| globe.cells[n].surface == Surface::Ocean)
+    });
+    let destination = game.player.position.destination(1.5, 2000.0 * KM, game.globe.radius);
+    head_for(&mut game, destination);
+    game.advance_state(12 * HOUR);
+    assert!(game.player.position_error(game.globe.radius) > 1.0 * KM);
+}
+
+#[test]
+fn walkers_along_a_river_know_where_they_are() {
+    let (mut game, cell) = game_at(|game, cell| game.globe.cells[cell].is_land() && game.globe.cells[cell].river);
+    wait_for_daylight(&mut game);
+    let radius = game.globe.radius;
+    let destination = game.globe.position_of(game.globe.topology.neighbors(cell)[0]);
+    game.player.believed_position = game.player.position.destination(0.5, 50.0 * KM, radius);
+    head_for(&mut game, destination);
+    game.advance_state(HOUR);
+    assert!(game.player.position_error(radius) < 1.0);
+}
+
+#[test]
+fn walkers_away_from_landmarks_stay_lost() {
+    let (mut game, cell) = game_at(|game, cell| {
+        let globe = &game.globe;
+        let position = globe.position_of(cell);
+        globe.cells[cell].is_land()
+            && !globe.cells[cell].river
+            && globe.topology.neighbors(cell).iter().all(|&n| globe.cells[n].is_land())
+            && game.settlements.within(&position, SIGHTING_RANGE, globe.radius).is_empty()
+    });
+    wait_for_daylight(&mut game);
+    let radius = game.globe.radius;
+    let destination = game.globe.position_of(game.globe.topology.neighbors(cell)[0]);
+    game.player.believed_position = game.player.position.destination(0.5, 50.0 * KM, radius);
+    head_for(&mut game, destination);
+    game.advance_state(HOUR);
+    assert!(game.player.position_error(radius) > 10.0 * KM);
+}
+
+#[test]
+fn reckoning_follows_from_the_world_seed() {
+    let drift = || {
+        let (mut game, _) = game_at(|game, cell| game.globe.cells[cell].surface == Surface::Ocean);
+        let destination = game.player.position.destination(1.5, 2000.0 * KM, game.globe.radius);
+        head_for(&mut game, destination);
+        game.advance_state(6 * HOUR);
+        (game.player.position, game.player.believed_position)
+    };
+    assert_eq!(drift(), drift());
+}
+
+#[test]
+fn walkers_stop_at_the_water() {
+    let (mut game, cell) = game_at(|game, cell| {
+        let globe = &game.globe;
+        globe.cells[cell].is_land() && globe.topology.neighbors(cell).iter().any(|&n| globe.cells[n].surface == Surface::Ocean)
+    });
+    let sea = game
+        .globe
+        .topology
+        .neighbors(cell)
+        .iter()
+        .copied()
+        .find(|&n| game.globe.cells[n].surface == Surface::Ocean)
+        .unwrap();
+    let destination = game.globe.position_of(sea);
+    head_for(&mut game, destination);
+
+    for _ in 0..20 * 24 {
+        game.advance_state(HOUR);
+        assert!(game.globe.cells[game.globe.cell_at(&game.player.position)].is_land(), "Walked into the sea");
+        if !matches!(game.player.task, Task::Traveling { .. }) {
+            break;
+        }
+    }
+    assert!(matches!(game.player.task, Task::Idle), "Still {}", game.player.task);
+    assert_eq!(game.events.last().map(|event| &event.kind), Some(&EventKind::StoppedAtWater { destination }));
+}
+
+#[test]
+fn running_aground_ends_the_voyage() {
+    // Open sea off a coast, and a point out to sea beyond the land the other way
+    let far_side = |game: &Game, cell: usize, land: usize| {
+        let globe = &game.globe;
+        let from = globe.position_of(cell);
+        let beyond = from.destination(from.initial_bearing_to(&globe.position_of(land)), 3000.0 * KM, globe.radius);
+        let end = globe.cell_at(&beyond);
+        (globe.cells[end].surface == Surface::Ocean && end != land).then_some(beyond)
+    };
+    let (mut game, cell) = game_at(|game, cell| {
+        let globe = &game.globe;
+        globe.cells[cell].surface == Surface::Ocean
+            && globe
+                .topology
+                .neighbors(cell)
+                .iter()
+                .any(|&n| globe.cells[n].is_land() && far_side(game, cell, n).is_some())
+    });
+    let land = game
+        .globe
+        .topology
+        .neighbors(cell)
+        .iter()
+        .copied()
+        .find(|&n| game.globe.cells[n].is_land() && far_side(&game, cell, n).is_some())
+        .unwrap();
+    let destination = far_side(&game, cell, land).unwrap();
+    // Setting out from just off the coast, so as not to take weeks to reach it
+    let offshore = game.player.position.interpolate(&game.globe.position_of(land), 0.45);
+    assert_eq!(game.globe.cell_at(&offshore), cell);
+    game.player.position = offshore;
+    game.player.believed_position = offshore;
+    head_for(&mut game, destination);
+
+    for _ in 0..20 * 24 {
+        game.advance_state(HOUR);
+        if !matches!(game.player.task, Task::Traveling { .. }) {
+            break;
+        }
+    }
+    assert!(matches!(game.player.task, Task::Idle), "Still {}", game.player.task);
+    assert_eq!(game.events.iter().filter(|event| event.kind == EventKind::RanAground).count(), 1);
+    assert!(game.player.ship.as_ref().unwrap().condition < 1.0);
+    assert!(!game.events.iter().any(|event| matches!(event.kind, EventKind::Arrived { .. })));
+}
@@ -0,0 +1,84 @@
+use gust_core::world::{
+    globe::{GenerationParams, Globe},
+    settlement::{SettlementKind, Settlements},
+};
+
+use common::at;
+
+mod common;
+
+fn settled() -> (Globe, Settlements) {
+    let globe = Globe::generate(13, &GenerationParams::default());
+    let settlements = Settlements::generate(&globe, 13);
+    assert!(!settlements.is_empty());
+    (globe, settlements)
+}
+
+#[test]
+fn at_cell_finds_the_settlement_on_it() {
+    let (globe, settlements) = settled();
+    for settlement in settlements.iter() {
+        assert_eq!(settlements.at_cell(settlement.cell), Some(settlement));
+        assert_eq!(globe.cell_at(&settlement.position), settlement.cell);
+        assert!(globe.cells[settlement.cell].is_land());
+    }
+
+    let empty = (0..globe.cells.len()).filter(|&cell| settlements.at_cell(cell).is_none()).count();
+    assert_eq!(empty + settlements.len(), globe.cells.len(), "Some cell holds more than one settlement");
+}
+
+#[test]
+fn no_settlements_on_water() {
+    let (globe, settlements) = settled();
+    for cell in (0..globe.cells.len()).filter(|&cell| !globe.cells[cell].is_land()) {
+        assert!(settlements.at_cell(cell).is_none());
+    }
+}
+
+#[test]
+fn nearest_is_the_closest_settlement() {
+    let (_, settlements) = settled();
+    for (latitude, longitude) in [(0.0, 0.0), (45.0, 90.0), (-30.0, -150.0), (70.0, 10.0), (-80.0, 120.0)] {
+        let position = at(latitude, longitude);
+        let nearest = settlements.nearest(&position).unwrap();
+        let distance = position.central_angle_to(&nearest.position);
+        assert!(settlements.iter().all(|other| position.central_angle_to(&other.position) >= distance));
+    }
+}
+
+#[test]
+fn nearest_to_a_settlement_is_itself() {
+    let (_, settlements) = settled();
+    for settlement in settlements.iter() {
+        assert_eq!(settlements.nearest(&settlement.position).map(|nearest| &nearest.name), Some(&settlement.name));
+    }
+}
+
+#[test]
+fn no_settlements_none_nearest() {
+    let settlements = Settlements::default();
+    assert!(settlements.nearest(&at(0.0, 0.0)).is_none());
+    assert!(settlements.at_cell(0).is_none());
+}
+
+#[test]
+fn kinds_follow_population() {
+    let (_, settlements) = settled();
+    for settlement in settlements.iter() {
+        assert_eq!(settlement.kind, SettlementKind::of_population(settlement.population));
+    }
+    assert!(SettlementKind::of_population(100) < SettlementKind::of_population(1_000_000));
+}
+
+#[test]
+fn no_two_settlements_are_neighbors() {
+    for seed in [1, 5, 9, 13] {
+        let globe = Globe::generate(seed, &GenerationParams::default());
+        let settlements = Settlements::generate(&globe, seed);
+        for settlement in settlements.iter() {
+            for &neighbor in globe.topology.neighbors(settlement.cell) {
+                assert!(settlements.at_cell(neighbor).is_none(), "seed {seed}: {} has a settled neighbor", settlement.name);
+            }
+        }
+    }
+}
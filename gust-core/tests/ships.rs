@@ -0,0 +1,97 @@
+use std::f32::consts::{PI, TAU};
+
+use gust_core::ships::{
+    polar::SpeedPolar,
+    ship::{HullClass, Ship},
+};
+
+use common::assert_close;
+
+mod common;
+
+/// Skill of a master sailor, at the top of the scale.
+const MAX_SKILL: f32 = 10.0;
+
+const HULLS: [HullClass; 4] = [HullClass::Sloop, HullClass::Caravel, HullClass::Cog, HullClass::Carrack];
+
+#[test]
+fn performance_stays_between_zero_and_one() {
+    for hull in HULLS {
+        let mut ship = Ship::new("Test", hull);
+        for crew in ship.min_crew..=ship.full_crew {
+            ship.crew = crew;
+            for condition in [0.01, 0.5, 1.0] {
+                ship.condition = condition;
+                for skill in [0.0, 0.25, 5.0, MAX_SKILL, 2.0 * MAX_SKILL] {
+                    let performance = ship.performance(skill);
+                    assert!((0.0..=1.0).contains(&performance), "{hull} performs at {performance}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn sound_fully_crewed_ship_performs_fully_under_a_master() {
+    for hull in HULLS {
+        assert_close(Ship::new("Test", hull).performance(MAX_SKILL), 1.0, 1e-6);
+    }
+}
+
+#[test]
+fn performance_rises_with_skill_crew_and_condition() {
+    let ship = Ship::new("Test", HullClass::Carrack);
+    assert!(ship.performance(1.0) < ship.performance(5.0));
+
+    let mut short_handed = ship.clone();
+    short_handed.crew = ship.min_crew;
+    assert!(short_handed.performance(5.0) < ship.performance(5.0));
+
+    let mut worn = ship.clone();
+    worn.condition = 0.5;
+    assert!(worn.performance(5.0) < ship.performance(5.0));
+}
+
+#[test]
+fn ship_that_cant_sail_performs_not_at_all() {
+    let mut undermanned = Ship::new("Test", HullClass::Cog);
+    undermanned.crew = undermanned.min_crew - 1;
+    assert!(!undermanned.can_sail());
+    assert_eq!(undermanned.performance(MAX_SKILL), 0.0);
+
+    let mut wrecked = Ship::new("Test", HullClass::Cog);
+    wrecked.condition = 0.0;
+    assert_eq!(wrecked.performance(MAX_SKILL), 0.0);
+}
+
+#[test]
+fn speed_never_passes_hull_speed() {
+    let polar = SpeedPolar::default();
+    for wind_speed in [0.0, 5.0, 15.0, 40.0, 100.0] {
+        for step in 0..72 {
+            let speed = polar.speed(step as f32 * TAU / 72.0, wind_speed);
+            assert!((0.0..=polar.max_speed).contains(&speed), "{speed} m/s in a {wind_speed} m/s wind");
+        }
+    }
+}
+
+#[test]
+fn speed_is_the_same_on_either_tack() {
+    let polar = SpeedPolar::default();
+    for step in 0..36 {
+        let angle = step as f32 * PI / 36.0;
+        assert_close(polar.speed(angle, 8.0), polar.speed(-angle, 8.0), 1e-4);
+        assert_close(polar.speed(angle, 8.0), polar.speed(angle + TAU, 8.0), 1e-4);
+    }
+}
+
+#[test]
+fn speed_follows_the_table_at_its_points() {
+    let polar = SpeedPolar::from_degrees(&[(0.0, 0.0), (90.0, 0.5), (180.0, 0.3)], 6.0);
+    assert_close(polar.speed(0.0, 10.0), 0.0, 1e-4);
+    assert_close(polar.speed(PI / 2.0, 10.0), 5.0, 1e-4);
+    assert_close(polar.speed(PI / 4.0, 10.0), 2.5, 1e-4);
+    assert_close(polar.speed(PI, 10.0), 3.0, 1e-4);
+    assert_close(polar.speed(PI / 2.0, 20.0), 6.0, 1e-4);
+    assert_close(polar.scaled(0.5).speed(PI / 2.0, 20.0), 3.0, 1e-4);
+}
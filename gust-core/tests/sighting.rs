@@ -0,0 +1,89 @@
+use gust_core::{
+    event::EventKind,
+    people::{
+        navigation::{self, Instrument},
+        skills::MAX_SKILL,
+        task::Task,
+    },
+    world::position::Position,
+    Game,
+};
+
+use common::{game_at, HOUR};
+
+mod common;
+
+/// Most sightings tried for one the clouds don't hide.
+const ATTEMPTS: usize = 30;
+
+/// A game with the player on land at low latitude, believing they are well off to the north-east.
+fn lost_on_land(instruments: Vec<Instrument>) -> Game {
+    let (mut game, _) = game_at(|game, cell| game.globe.cells[cell].is_land() && game.globe.position_of(cell).latitude().abs() < 0.5);
+    let position = game.player.position;
+    game.player.believed_position = Position::new(position.latitude() + 0.2, position.longitude() + 0.2);
+    game.player.instruments = instruments;
+    game
+}
+
+/// Takes sightings until one of them gives a fix, returning whether it fixed the longitude too.
+fn take_fix(game: &mut Game) -> bool {
+    for _ in 0..ATTEMPTS {
+        game.start_sighting();
+        while matches!(game.player.task, Task::Sighting { .. }) {
+            game.advance_state(HOUR);
+        }
+        if let Some(EventKind::Fix { longitude, .. }) = game.events.last().map(|event| &event.kind) {
+            return *longitude;
+        }
+    }
+    panic!("Clouds hid every sighting");
+}
+
+#[test]
+fn sighting_without_chronometer_fixes_latitude_only() {
+    let mut game = lost_on_land(vec![Instrument::Quadrant]);
+    let (position, believed) = (game.player.position, game.player.believed_position);
+    assert!(!take_fix(&mut game));
+
+    let fix = game.player.believed_position;
+    assert!((fix.latitude() - position.latitude()).abs() < 0.1);
+    assert_eq!(fix.longitude(), believed.longitude());
+}
+
+#[test]
+fn sighting_with_chronometer_fixes_longitude_too() {
+    let mut game = lost_on_land(vec![Instrument::Quadrant, Instrument::Chronometer]);
+    let position = game.player.position;
+    assert!(take_fix(&mut game));
+
+    let fix = game.player.believed_position;
+    assert!((fix.latitude() - position.latitude()).abs() < 0.1);
+    assert!((fix.longitude() - position.longitude()).abs() < 0.1);
+}
+
+#[test]
+fn only_a_chronometer_gives_a_longitude_error() {
+    let (_, without) = navigation::sighting_error(&[Instrument::Octant], 1.0, 0.0);
+    let (latitude, with) = navigation::sighting_error(&[Instrument::Octant, Instrument::Chronometer], 1.0, 0.0);
+    assert!(without.is_none());
+    assert!(with.is_some_and(|longitude| longitude > latitude));
+}
+
+#[test]
+fn better_instruments_skill_and_weather_sharpen_sightings() {
+    let error = |instruments: &[Instrument], skill, danger| navigation::sighting_error(instruments, skill, danger).0;
+    assert!(error(&[Instrument::Octant], 1.0, 0.0) < error(&[Instrument::Quadrant], 1.0, 0.0));
+    assert!(error(&[Instrument::Quadrant], 1.0, 0.0) < error(&[], 1.0, 0.0));
+    assert!(error(&[Instrument::Quadrant], MAX_SKILL, 0.0) < error(&[Instrument::Quadrant], 1.0, 0.0));
+    assert!(error(&[Instrument::Quadrant], 1.0, 0.0) < error(&[Instrument::Quadrant], 1.0, 1.0));
+}
+
+#[test]
+fn sightings_follow_from_the_world_seed() {
+    let fix = || {
+        let mut game = lost_on_land(vec![Instrument::Quadrant, Instrument::Chronometer]);
+        take_fix(&mut game);
+        game.player.believed_position
+    };
+    assert_eq!(fix(), fix());
+}
@@ -0,0 +1,50 @@
+use gust_core::world::{
+    globe::{GenerationParams, Globe},
+    tectonics::TectonicParams,
+};
+
+fn plates(globe: &Globe) -> Vec<Option<usize>> {
+    globe.cells.iter().map(|cell| cell.plate).collect()
+}
+
+#[test]
+fn plates_are_deterministic() {
+    let params = GenerationParams::default();
+    let a = Globe::generate(3, &params);
+    let b = Globe::generate(3, &params);
+    let c = Globe::generate(4, &params);
+
+    assert_eq!(plates(&a), plates(&b));
+    assert!(a.plates.iter().zip(&b.plates).all(|(a, b)| a.kind == b.kind && a.axis == b.axis));
+    assert_ne!(plates(&a), plates(&c));
+}
+
+#[test]
+fn every_cell_belongs_to_one_of_the_plates() {
+    let globe = Globe::generate(3, &GenerationParams::default());
+    assert_eq!(globe.plates.len(), TectonicParams::default().plate_count);
+    for plate in 0..globe.plates.len() {
+        assert!(globe.cells.iter().any(|cell| cell.plate == Some(plate)), "plate {plate} has no cells");
+    }
+    assert!(globe.cells.iter().all(|cell| cell.plate.is_some_and(|plate| plate < globe.plates.len())));
+}
+
+#[test]
+fn plates_turn_about_unit_axes() {
+    let globe = Globe::generate(3, &GenerationParams::default());
+    for plate in &globe.plates {
+        assert!((plate.axis.length() - 1.0).abs() < 1e-4, "axis of length {}", plate.axis.length());
+        assert!(plate.angular_speed > 0.0);
+    }
+}
+
+#[test]
+fn skipping_tectonics_leaves_the_cells_without_plates() {
+    let params = GenerationParams {
+        tectonics: None,
+        ..GenerationParams::default()
+    };
+    let globe = Globe::generate(3, &params);
+    assert!(globe.plates.is_empty());
+    assert!(globe.cells.iter().all(|cell| cell.plate.is_none()));
+}
@@ -0,0 +1,73 @@
+use gust_core::{
+    event::EventKind,
+    people::{plan::PlannedTask, task::Task},
+    world::{globe::Surface, position::Course},
+    Game,
+};
+
+use common::HOUR;
+
+mod common;
+
+/// A game with the player on the shore of a coast with a wide tidal range, in a sloop drawing as much water as the
+/// harbor channel there, at low water. Returns the game and the ocean cell off the shore.
+fn low_tide_game() -> (Game, usize) {
+    let mut game = Game::new(0, 3);
+    let globe = &game.globe;
+    let tides = &game.tides;
+
+    let (cell, sea) = (0..globe.cells.len())
+        .filter(|&cell| globe.cells[cell].is_land() && tides.coast(cell).is_some_and(|coast| coast.spring_range > 2.0))
+        .find_map(|cell| {
+            globe
+                .topology
+                .neighbors(cell)
+                .iter()
+                .find(|&&n| globe.cells[n].surface == Surface::Ocean)
+                .map(|&sea| (cell, sea))
+        })
+        .expect("No coast with a wide tidal range");
+    let draft = tides.coast(cell).unwrap().channel_depth as f64;
+    let low_water = (0..48 * HOUR)
+        .step_by(HOUR as usize)
+        .find(|&time| !tides.can_pass(globe, cell, time, draft) && tides.next_passable(globe, cell, time, draft).is_some())
+        .expect("The tide never turns");
+
+    let position = globe.position_of(cell);
+    game.time_in_seconds = low_water;
+    game.player.position = position;
+    game.player.believed_position = position;
+    game.player.ship.as_mut().unwrap().draft = draft;
+    (game, sea)
+}
+
+#[test]
+fn deep_sloop_waits_for_the_tide_to_leave() {
+    let (mut game, sea) = low_tide_game();
+    let destination = game.globe.position_of(sea);
+    let cell = game.globe.cell_at(&game.player.position);
+    let draft = game.player.ship.as_ref().unwrap().draft;
+    let window = game.tides.next_passable(&game.globe, cell, game.time_in_seconds, draft).unwrap();
+
+    assert!(game.travel_to(destination, Course::GreatCircle));
+    let Task::WaitingForTide { until } = game.player.task else {
+        panic!("Set out at low water: {}", game.player.task);
+    };
+    assert_eq!(until, window);
+    assert!(matches!(game.player.plan.get(0), Some(PlannedTask::Travel { .. })));
+
+    game.advance_state(until - game.time_in_seconds);
+    assert!(matches!(game.player.task, Task::Traveling { .. } | Task::Idle), "Still {}", game.player.task);
+    assert!(game.player.plan.is_empty());
+    assert!(!game.events.iter().any(|event| matches!(event.kind, EventKind::NoRoute { .. })));
+}
+
+#[test]
+fn shallow_sloop_sails_at_low_tide() {
+    let (mut game, sea) = low_tide_game();
+    game.player.ship.as_mut().unwrap().draft = 0.0;
+    let destination = game.globe.position_of(sea);
+
+    assert!(game.travel_to(destination, Course::GreatCircle));
+    assert!(matches!(game.player.task, Task::Traveling { .. }), "Held up: {}", game.player.task);
+}
@@ -0,0 +1,64 @@
+use gust_core::{
+    event::EventKind,
+    people::{skills::Skill, task::Task},
+    Game,
+};
+
+use common::{game_at, head_for, wait_for_daylight, HOUR};
+
+mod common;
+
+/// A game with the player inland by day, among cells of land on every side.
+fn inland() -> (Game, usize) {
+    let (mut game, cell) = game_at(|game, cell| {
+        let globe = &game.globe;
+        globe.cells[cell].is_land() && globe.topology.neighbors(cell).iter().all(|&n| globe.cells[n].is_land())
+    });
+    wait_for_daylight(&mut game);
+    (game, cell)
+}
+
+#[test]
+fn walking_tires_and_teaches() {
+    let (mut game, cell) = inland();
+    let destination = game.globe.position_of(game.globe.topology.neighbors(cell)[0]);
+    // Some experience already, as the first hours on the road teach too little to show
+    game.player.skillset.traveling = Skill::new(1.0, 1.0);
+    let (start, skill) = (game.player.position, game.player.skillset.traveling.get());
+    head_for(&mut game, destination);
+
+    game.advance_state(HOUR);
+    assert_ne!(game.player.position, start);
+    assert!(game.player.fatigue > 0.0);
+    assert!(game.player.skillset.traveling.get() > skill);
+}
+
+#[test]
+fn arriving_ends_the_travels() {
+    let (mut game, _) = inland();
+    let destination = game.player.position.destination(1.0, 1000.0, game.globe.radius);
+    head_for(&mut game, destination);
+
+    game.advance_state(HOUR);
+    assert!(matches!(game.player.task, Task::Idle), "Still {}", game.player.task);
+    // Off only by the heading they kept on the way
+    assert!(game.player.position.distance_to(&destination, game.globe.radius) < 250.0);
+    assert_eq!(game.events.last().map(|event| &event.kind), Some(&EventKind::Arrived { destination }));
+}
+
+#[test]
+fn walkers_rest_at_night() {
+    let (mut game, cell) = inland();
+    let position = game.player.position;
+    while game.is_daylight(&position) || game.globe.astronomy.is_daylight(game.time_in_seconds + HOUR, &position) {
+        game.time_in_seconds += HOUR;
+    }
+    let destination = game.globe.position_of(game.globe.topology.neighbors(cell)[0]);
+    game.player.fatigue = 50.0;
+    head_for(&mut game, destination);
+
+    game.advance_state(HOUR);
+    assert_eq!(game.player.position, position);
+    assert!(game.player.fatigue < 50.0);
+    assert!(matches!(game.player.task, Task::Traveling { .. }));
+}
@@ -0,0 +1,95 @@
+use gust_core::world::{
+    globe::{GenerationParams, Globe},
+    weather::{Weather, WeatherKind, WeatherSystem},
+    wind::WindField,
+};
+
+use common::at;
+
+mod common;
+
+const DAY: u64 = 24 * 60 * 60;
+
+fn summary(weather: &Weather) -> Vec<(WeatherKind, f64, f64, u64)> {
+    weather
+        .systems
+        .iter()
+        .map(|system| (system.kind, system.center.latitude(), system.center.longitude(), system.lifetime))
+        .collect()
+}
+
+#[test]
+fn weather_is_deterministic() {
+    let globe = Globe::generate(4, &GenerationParams::default());
+    let wind = WindField::compute(&globe, 0);
+    let mut a = Weather::new(4);
+    let mut b = Weather::new(4);
+    let mut c = Weather::new(5);
+    for weather in [&mut a, &mut b, &mut c] {
+        weather.advance(&globe, &wind, 20 * DAY);
+    }
+
+    assert!(!a.systems.is_empty());
+    assert_eq!(summary(&a), summary(&b));
+    assert_ne!(summary(&a), summary(&c));
+}
+
+#[test]
+fn systems_die_out_within_their_lifetimes() {
+    let globe = Globe::generate(4, &GenerationParams::default());
+    let wind = WindField::compute(&globe, 0);
+    let mut weather = Weather::new(4);
+    for _ in 0..30 {
+        weather.advance(&globe, &wind, DAY);
+        for system in &weather.systems {
+            assert!(system.age < system.lifetime);
+            assert!((0.0..=1.0).contains(&system.maturity()));
+        }
+    }
+}
+
+#[test]
+fn conditions_stay_within_bounds() {
+    let globe = Globe::generate(4, &GenerationParams::default());
+    let wind = WindField::compute(&globe, 0);
+    let mut weather = Weather::new(4);
+    weather.advance(&globe, &wind, 20 * DAY);
+
+    for latitude in (-80..=80).step_by(10) {
+        for longitude in (-180..180).step_by(15) {
+            let conditions = weather.conditions_at(&globe, &wind, &at(latitude as f64, longitude as f64));
+            assert!((0.0..=1.0).contains(&conditions.danger));
+            assert!(conditions.precipitation >= 0.0);
+        }
+    }
+}
+
+#[test]
+fn calms_still_the_wind_and_storms_raise_it() {
+    let globe = Globe::new();
+    let wind = WindField::compute(&globe, 0);
+    let center = at(45.0, 0.0);
+    let near = at(44.0, 0.0);
+    let system = |kind: WeatherKind, strength: f32| WeatherSystem {
+        kind,
+        center,
+        radius: 800e3,
+        strength,
+        age: 2 * DAY,
+        lifetime: 4 * DAY,
+    };
+
+    let mut weather = Weather::new(0);
+    let clear = weather.conditions_at(&globe, &wind, &near);
+    assert_eq!(clear.system, None);
+
+    weather.systems = vec![system(WeatherKind::Calm, 0.0)];
+    let calm = weather.conditions_at(&globe, &wind, &near);
+    assert_eq!(calm.system, Some(WeatherKind::Calm));
+    assert!(calm.wind.speed() < clear.wind.speed());
+
+    weather.systems = vec![system(WeatherKind::Storm, 25.0)];
+    let storm = weather.conditions_at(&globe, &wind, &near);
+    assert_eq!(storm.system, Some(WeatherKind::Storm));
+    assert!(storm.danger > clear.danger && storm.precipitation > clear.precipitation);
+}
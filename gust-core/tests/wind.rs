@@ -0,0 +1,45 @@
+use std::f32::consts::TAU;
+
+use gust_core::world::{
+    globe::{GenerationParams, Globe},
+    wind::{self, Wind, WindField},
+};
+
+use common::{assert_close, at};
+
+mod common;
+
+#[test]
+fn wind_field_is_deterministic() {
+    let globe = Globe::generate(2, &GenerationParams::default());
+    let a = WindField::compute(&globe, 1000);
+    let b = WindField::compute(&globe, 1000);
+    assert!((0..globe.cell_count()).all(|cell| a.cell(cell) == b.cell(cell)));
+}
+
+#[test]
+fn winds_blow_in_their_belts_over_the_open_sea() {
+    let globe = Globe::new();
+    for time in [0, 8_000_000, 16_000_000, 24_000_000] {
+        for hemisphere in [1.0f32, -1.0] {
+            let wind = |latitude: f64| wind::prevailing_wind_at(&globe, &at(hemisphere as f64 * latitude, 30.0), time);
+            let (trades, westerlies, easterlies) = (wind(15.0), wind(45.0), wind(75.0));
+
+            assert!(trades.east < 0.0 && trades.north * hemisphere < 0.0, "trade wind {trades}");
+            assert!(westerlies.east > 0.0 && westerlies.north * hemisphere > 0.0, "westerly {westerlies}");
+            assert!(easterlies.east < 0.0, "polar easterly {easterlies}");
+        }
+    }
+}
+
+#[test]
+fn direction_is_where_the_wind_blows_from() {
+    assert_close(Wind::new(0.0, -5.0).direction(), 0.0, 1e-6);
+    assert_close(Wind::new(-5.0, 0.0).direction(), TAU / 4.0, 1e-6);
+    for step in 0..16 {
+        let direction = step as f32 * TAU / 16.0;
+        let wind = Wind::from_direction(direction, 6.0);
+        assert_close(wind.speed(), 6.0, 1e-4);
+        assert_close(wind.direction(), direction, 1e-4);
+    }
+}
@@ -27,7 +27,7 @@ fn main() -> Result<()> {
         let event = read()?;
         match event {
             Event::Key(key_event) => match key_event.code {
-                KeyCode::Char('q') => {
+                KeyCode::Char('q') if !tui.is_typing() => {
                     break 'input_loop;
                 }
                 _ => {
@@ -3,29 +3,62 @@ use crossterm::{
     event::{Event, KeyCode},
     execute, queue,
     style::{self, Color},
-    terminal::{self, SetTitle},
-    Result,
+    terminal, Result,
+};
+use gust_core::{
+    people::{
+        plan::PlannedTask,
+        task::{Task, LISTENING_DURATION},
+    },
+    util::Fractionable,
+    world::position::{Course, Position},
+};
+use std::{
+    io::{stdout, Write},
+    time::{SystemTime, UNIX_EPOCH},
 };
-use gust_core::util::Fractionable;
-use std::io::{stdout, Write};
 
 use self::{
+    forecast::{ForecastPanel, ForecastView},
     log::{Log, LogPanel},
-    menu::{Menu, MenuPanel},
+    menu::{Menu, MenuChoice, MenuPanel},
     panel::PanelDims,
     status::{Status, StatusPanel},
 };
 
+mod forecast;
 mod log;
 mod menu;
 mod panel;
+mod prompt;
 mod status;
 
 const BG_COLOR: Color = Color::Rgb { r: 10, g: 40, b: 50 };
 const BORDER_COLOR: Color = Color::Rgb { r: 120, g: 170, b: 200 };
 
+// Game time in seconds let pass by the keys for waiting
+const HOUR: u64 = 60 * 60;
+const DAY: u64 = 24 * HOUR;
+// Game time in seconds spent on the tasks picked from the menu
+const REST_DURATION: u64 = 8 * HOUR;
+const TRADE_DURATION: u64 = 4 * HOUR;
+// Longest the plan is run for at once
+const PLAN_RUN_LIMIT: u64 = 60 * DAY;
+
+// Indices of the options of the menu
+const MENU_MOVE: usize = 0;
+const MENU_LISTEN: usize = 1;
+const MENU_SIGHT: usize = 2;
+const MENU_REST: usize = 3;
+const MENU_TRADE: usize = 4;
+const MENU_RUN_PLAN: usize = 5;
+
+// Courses offered by the destination prompt, in the order Tab cycles through them
+const COURSES: [(Course, &str); 2] = [(Course::GreatCircle, "Great circle"), (Course::Rhumb, "Rhumb line")];
+
 pub struct Tui {
     game: gust_core::Game,
+    forecast_panel: ForecastPanel,
     log_panel: LogPanel,
     menu_panel: MenuPanel,
     status_panel: StatusPanel,
@@ -36,12 +69,20 @@ impl Tui {
         execute!(stdout(), style::SetColors(style::Colors::new(BORDER_COLOR, BG_COLOR))).expect("Could not set TUI colors");
 
         Self {
-            game: gust_core::Game::new(0),
+            game: gust_core::Game::new(0, new_world_seed()),
+            forecast_panel: ForecastPanel { kind: ForecastView {} },
             log_panel: LogPanel { kind: Log::new() },
             menu_panel: MenuPanel {
-                kind: Menu::new(vec!["Move".to_string(), "Listen".to_string(), "Rest".to_string()]),
+                kind: Menu::new(vec![
+                    "Move".to_string(),
+                    "Listen".to_string(),
+                    "Sight".to_string(),
+                    "Rest".to_string(),
+                    "Trade".to_string(),
+                    "Run plan".to_string(),
+                ]),
             },
-            status_panel: StatusPanel { kind: Status {} },
+            status_panel: StatusPanel { kind: Status::new() },
         }
     }
 
@@ -80,6 +121,19 @@ impl Tui {
             )
             .expect("Could not draw status panel");
 
+        // Fills the space between the log panel and the status panel
+        self.forecast_panel
+            .draw(
+                PanelDims {
+                    x: main_w.fraction(LOG_PANEL_WIDTH_FRACTION) - 1,
+                    y: 0,
+                    w: main_w.fraction(STATUS_PANEL_WIDTH_FRACTION) - main_w.fraction(LOG_PANEL_WIDTH_FRACTION) + 1,
+                    h: status_h,
+                },
+                &self.game,
+            )
+            .expect("Could not draw forecast panel");
+
         self.menu_panel
             .draw(
                 PanelDims {
@@ -95,22 +149,136 @@ impl Tui {
         stdout().flush()
     }
 
+    /// Whether keys are being typed into a prompt, rather than acting as commands.
+    pub fn is_typing(&self) -> bool {
+        self.menu_panel.kind.is_prompting()
+    }
+
     pub fn handle_key_event(&mut self, event: crossterm::event::Event) {
-        match event {
-            Event::Key(key_event) => match key_event.code {
+        if self.is_typing() {
+            if let Some(choice) = self.menu_panel.kind.handle_key_event(event) {
+                self.handle_menu_choice(choice);
+            }
+            return;
+        }
+
+        if let Event::Key(key_event) = event {
+            match key_event.code {
                 KeyCode::Char('4') => {
                     self.log_panel.kind.rotate(-1.0);
                 }
                 KeyCode::Char('6') => {
                     self.log_panel.kind.rotate(1.0);
                 }
+                KeyCode::Char('c') => {
+                    self.log_panel.kind.toggle_currents();
+                }
+                KeyCode::Char('h') => {
+                    self.game.advance_state(HOUR);
+                }
+                KeyCode::Char('d') => {
+                    self.game.advance_state(DAY);
+                }
+                KeyCode::Char('[') => {
+                    self.status_panel.kind.select_previous(self.game.player.plan.len());
+                }
+                KeyCode::Char(']') => {
+                    self.status_panel.kind.select_next(self.game.player.plan.len());
+                }
+                KeyCode::Char('<') => self.move_selected_task(-1),
+                KeyCode::Char('>') => self.move_selected_task(1),
+                KeyCode::Char('x') => {
+                    // Cancels the task picked out in the plan, or else the last one.
+                    let plan = &mut self.game.player.plan;
+                    let index = self.status_panel.kind.selected(plan.len()).unwrap_or(plan.len().saturating_sub(1));
+                    plan.cancel(index);
+                }
                 _ => {
-                    self.menu_panel.kind.handle_key_event(event);
+                    if let Some(choice) = self.menu_panel.kind.handle_key_event(event) {
+                        self.handle_menu_choice(choice);
+                    }
                 }
+            }
+        }
+    }
+
+    fn handle_menu_choice(&mut self, choice: MenuChoice) {
+        match choice {
+            MenuChoice::Option(MENU_MOVE) => {
+                let courses = COURSES.iter().map(|(_, name)| name.to_string()).collect();
+                self.menu_panel.kind.open_prompt("Destination (e.g. 51°30'N 0°07'W):", courses);
+            }
+            MenuChoice::Option(MENU_LISTEN) => {
+                // Listening right away takes the time it needs, as there is little else to do meanwhile.
+                if self.game.player.is_idle() {
+                    self.game.start_listening();
+                    self.game.advance_state(LISTENING_DURATION);
+                } else {
+                    self.add_to_plan(PlannedTask::Listen);
+                }
+            }
+            MenuChoice::Option(MENU_SIGHT) => {
+                // Travelers stop for a sighting along the way, and go on after it.
+                if matches!(self.game.player.task, Task::Idle | Task::Traveling { .. }) {
+                    self.game.start_sighting();
+                } else {
+                    self.add_to_plan(PlannedTask::Sight);
+                }
+            }
+            MenuChoice::Option(MENU_REST) => self.plan(PlannedTask::Rest { duration: REST_DURATION }),
+            MenuChoice::Option(MENU_TRADE) => self.plan(PlannedTask::Trade { duration: TRADE_DURATION }),
+            MenuChoice::Option(MENU_RUN_PLAN) => {
+                self.game.advance_until_idle(PLAN_RUN_LIMIT);
+            }
+            MenuChoice::Option(_) => (),
+            MenuChoice::Text(text, course) => match text.parse::<Position>() {
+                Ok(destination) => self.travel_to(destination, COURSES[course].0),
+                Err(error) => self.menu_panel.kind.set_prompt_error(format!("Invalid destination: {}", error)),
             },
-            _ => {}
+        }
+    }
+
+    /// Adds a task to the player's plan, setting about it right away if they have nothing else to do.
+    fn plan(&mut self, task: PlannedTask) {
+        self.add_to_plan(task);
+        self.game.start_next_task();
+    }
+
+    /// Adds a task to the player's plan before the task picked out in it, or at the end if none is.
+    fn add_to_plan(&mut self, task: PlannedTask) {
+        let plan = &mut self.game.player.plan;
+        match self.status_panel.kind.selected(plan.len()) {
+            Some(index) => plan.insert(index, task),
+            None => plan.push(task),
+        }
+    }
+
+    /// Moves the task picked out in the plan a place earlier or later, keeping it picked out.
+    fn move_selected_task(&mut self, offset: isize) {
+        let plan = &mut self.game.player.plan;
+        let Some(from) = self.status_panel.kind.selected(plan.len()) else {
+            return;
         };
+        let to = from.saturating_add_signed(offset).min(plan.len() - 1);
+        plan.reorder(from, to);
+        self.status_panel.kind.select(Some(to));
     }
+
+    /// Sets the player off towards a destination if they have nothing else to do, or else plans to go there.
+    fn travel_to(&mut self, destination: Position, course: Course) {
+        if !self.game.player.is_idle() {
+            self.add_to_plan(PlannedTask::Travel { destination, course });
+        } else if !self.game.travel_to(destination, course) {
+            self.menu_panel.kind.set_prompt_error("No way there over land or sea".to_string());
+            return;
+        }
+        self.menu_panel.kind.close_prompt();
+    }
+}
+
+/// Derives a world seed from the system clock, so each run starts on a different globe.
+fn new_world_seed() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
 }
 
 fn draw_panel(x: u16, y: u16, w: u16, h: u16) -> Result<()> {
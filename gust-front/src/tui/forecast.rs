@@ -0,0 +1,44 @@
+use std::io::stdout;
+
+use crossterm::{cursor, queue, style};
+
+use super::{
+    draw_panel,
+    panel::{Panel, PanelDims},
+};
+
+/// Shows the last forecast the player made by listening to the wind.
+pub struct ForecastView {}
+
+impl Panel<ForecastView> {
+    pub fn draw(&self, dims: PanelDims, game: &gust_core::Game) -> Result<(), std::io::Error> {
+        draw_panel(dims.x, dims.y, dims.w, dims.h)?;
+
+        queue!(stdout(), cursor::MoveTo(dims.x + 2, dims.y + 2), style::Print("Forecast"))?;
+
+        let upcoming: Vec<_> = match &game.player.forecast {
+            Some(forecast) => forecast.entries.iter().filter(|entry| entry.time_in_seconds >= game.time_in_seconds).collect(),
+            None => Vec::new(),
+        };
+
+        if upcoming.is_empty() {
+            return queue!(stdout(), cursor::MoveTo(dims.x + 2, dims.y + 4), style::Print("Listen to the wind"));
+        }
+
+        // Leave room for the title and the borders
+        let rows = dims.h.saturating_sub(5) as usize;
+        for (row, entry) in upcoming.iter().take(rows).enumerate() {
+            let hours = (entry.time_in_seconds - game.time_in_seconds) / 3600;
+            let system = entry.system.map(|system| format!(" {}", system)).unwrap_or_default();
+            queue!(
+                stdout(),
+                cursor::MoveTo(dims.x + 2, dims.y + 4 + row as u16),
+                style::Print(format!("+{:<3} {}{}", format!("{}h", hours), entry.wind, system))
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+pub type ForecastPanel = Panel<ForecastView>;
@@ -1,13 +1,14 @@
-use std::{
-    f32::{consts::PI, INFINITY, NEG_INFINITY},
-    io::stdout,
-};
+use std::{f32::consts::PI, io::stdout};
 
 use crossterm::{
     cursor, queue,
     style::{self, Stylize},
 };
-use glam::{Mat2, Mat3A, Mat4, Vec2, Vec3, Vec3A};
+use glam::{Mat2, Mat4, Vec3, Vec3A};
+use gust_core::{
+    people::task::Task,
+    world::{biome::Biome, globe::Surface, pathfinding::Route, position::Position, settlement::SettlementKind},
+};
 
 use crate::tui::{BG_COLOR, BORDER_COLOR};
 
@@ -17,26 +18,25 @@ use super::{
 };
 
 pub struct Log {
-    /// Offset from the end of the log. Hence, 0 means we're displaying
-    /// the last line of the log at the bottom of the panel.
-    index: u64,
-
     pub camera_position: Vec3A,
+    /// Whether ocean currents are drawn over the globe.
+    show_currents: bool,
     view_matrix: Mat4,
     camera_matrix: Mat4,
     depth_buffer: ndarray::Array2<f32>,
 }
 
 const CAM_DISTANCE_TO_ORIGIN: f32 = 2.0;
+const ROUTE_COLOR: style::Color = style::Color::Rgb { r: 255, g: 220, b: 90 };
+const SETTLEMENT_COLOR: style::Color = style::Color::Rgb { r: 240, g: 110, b: 90 };
 const WORLD_ORIGIN: Vec3 = Vec3::new(0.0, 0.0, 0.0);
 const CAMERA_UP_DIR: Vec3 = Vec3::new(0.0, 1.0, 0.0);
 
 impl Log {
     pub fn new() -> Self {
         Self {
-            index: 0,
-
             camera_position: Vec3A::new(0.0, 0.0, -CAM_DISTANCE_TO_ORIGIN),
+            show_currents: false,
             view_matrix: Mat4::ZERO,
 
             // Converts points from camera space to screen space.
@@ -50,6 +50,10 @@ impl Log {
         self.camera_position = Mat4::from_rotation_y(direction * PI / 20.0).transform_point3a(self.camera_position);
     }
 
+    pub fn toggle_currents(&mut self) {
+        self.show_currents = !self.show_currents;
+    }
+
     fn transform_world_vertex_to_camera_space(&self, v: Vec3A) -> Vec3A {
         self.view_matrix.transform_point3a(v)
     }
@@ -58,7 +62,7 @@ impl Log {
         let mut result = self.camera_matrix.project_point3(v.into());
         result.x = (result.x + 1.0) / 2.0 * dims.w as f32 + dims.x as f32;
         result.y = dims.h as f32 - (result.y + 1.0) / 2.0 * dims.h as f32 + dims.y as f32;
-        return result.into();
+        result.into()
     }
 
     fn is_point_in_triangle(point: Vec3A, triangle_a: Vec3A, triangle_b: Vec3A, triangle_c: Vec3A) -> bool {
@@ -80,7 +84,7 @@ impl Log {
             return true;
         }
 
-        return point_to_side_of_edge_ab < 0.0 && point_to_side_of_edge_bc < 0.0 && point_to_side_of_edge_ca < 0.0;
+        point_to_side_of_edge_ab < 0.0 && point_to_side_of_edge_bc < 0.0 && point_to_side_of_edge_ca < 0.0
     }
 
     pub fn draw(&mut self, dims: PanelDims, game: &gust_core::Game) -> Result<(), std::io::Error> {
@@ -91,7 +95,14 @@ impl Log {
         let vertices = game.globe.data.raw_points();
         let triangle_indices = game.globe.data.get_all_indices();
 
-        self.depth_buffer.fill(std::f32::INFINITY); // Clear the depth buffer
+        self.depth_buffer.fill(f32::INFINITY); // Clear the depth buffer
+
+        let sun_direction = game.globe.astronomy.sun_direction(game.time_in_seconds);
+        let cell_color = |cell: usize| {
+            let light = daylight(vertices[cell].dot(sun_direction));
+            let current_speed = if self.show_currents { game.currents.cell(cell).speed() } else { 0.0 };
+            color(game.globe.cells[cell].biome, current_speed, light)
+        };
 
         // Rasterize each triangle
         for triangle in triangle_indices.chunks(3) {
@@ -130,18 +141,20 @@ impl Log {
                     if Log::is_point_in_triangle(Vec3A::new(pixel_x_f32, pixel_y_f32, 0.0), v0_screen, v1_screen, v2_screen) {
                         // TODO: do we need a more sophisticated depth?
                         let depth = (v0_screen.z + v1_screen.z + v2_screen.z) / 3.0;
-                        let buffer_depth = self.depth_buffer[(pixel_x as usize, pixel_y as usize)];
                         if depth < self.depth_buffer[(pixel_x as usize, pixel_y as usize)] {
-                            if buffer_depth.is_infinite() == false {
-                                let pp = 1;
-                            }
                             self.depth_buffer[(pixel_x as usize, pixel_y as usize)] = depth;
 
-                            let styled = " ".on(style::Color::Rgb {
-                                r: (v0.x * 255.0) as u8,
-                                g: (v0.y * 255.0) as u8,
-                                b: (v0.z * 255.0) as u8,
-                            });
+                            // Color the pixel after the nearest corner of the triangle, so that the cells around each corner show up.
+                            let pixel = Vec3A::new(pixel_x_f32, pixel_y_f32, 0.0);
+                            let nearest_corner = [v0_screen, v1_screen, v2_screen]
+                                .iter()
+                                .map(|corner| (*corner - pixel).truncate().length_squared())
+                                .enumerate()
+                                .min_by(|a, b| a.1.total_cmp(&b.1))
+                                .map(|(index, _)| triangle[index] as usize)
+                                .unwrap();
+
+                            let styled = " ".on(cell_color(nearest_corner));
                             queue!(stdout(), cursor::MoveTo(pixel_x, pixel_y), style::PrintStyledContent(styled))?
                         }
                     }
@@ -149,10 +162,165 @@ impl Log {
             }
         }
 
+        if self.show_currents {
+            self.draw_current_arrows(&dims, game, &cell_color)?;
+        }
+        self.draw_settlements(&dims, game, &cell_color)?;
+        if let Task::Traveling { route: Some(route), .. } = &game.player.task {
+            self.draw_route(&dims, game, route, &cell_color)?;
+        }
+
         // Set the BG color again because printing styled background resets it back to the terminal default
         queue!(stdout(), style::SetColors(style::Colors::new(BORDER_COLOR, BG_COLOR))).expect("Could not set TUI colors");
+        self.draw_events(&dims, game)
+    }
+
+    /// Draws an arrow over the center of each ocean cell facing the camera, pointing the way its current flows.
+    fn draw_current_arrows(&self, dims: &PanelDims, game: &gust_core::Game, cell_color: &impl Fn(usize) -> style::Color) -> Result<(), std::io::Error> {
+        const ARROWS: [char; 8] = ['↑', '↗', '→', '↘', '↓', '↙', '←', '↖'];
+        const ARROW_LENGTH: f32 = 0.02;
+
+        let vertices = game.globe.data.raw_points();
+        for (cell, point) in vertices.iter().enumerate() {
+            let current = game.currents.cell(cell);
+            if game.globe.cells[cell].surface != Surface::Ocean || current.speed() < 0.05 || point.dot(self.camera_position) <= 1.0 {
+                continue;
+            }
+
+            let (east, north) = game.globe.position_of(cell).local_axes();
+            let tip = *point + (east * current.east + north * current.north).normalize() * ARROW_LENGTH;
+
+            let start = self.project_camera_vertex_to_screen_space(self.transform_world_vertex_to_camera_space(*point), dims);
+            let end = self.project_camera_vertex_to_screen_space(self.transform_world_vertex_to_camera_space(tip), dims);
+            if start.x < (dims.x + 1) as f32 || start.y < (dims.y + 1) as f32 || start.x >= (dims.x + dims.w - 1) as f32 || start.y >= (dims.y + dims.h - 1) as f32 {
+                continue;
+            }
+
+            // Screen rows grow downwards, and each row is about twice as tall as a column is wide.
+            let angle = (end.x - start.x).atan2(2.0 * (start.y - end.y));
+            let arrow = ARROWS[((angle / (PI / 4.0)).round() as i32).rem_euclid(8) as usize];
+            let styled = arrow.with(style::Color::White).on(cell_color(cell));
+            queue!(stdout(), cursor::MoveTo(start.x as u16, start.y as u16), style::PrintStyledContent(styled))?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the latest events at the bottom of the panel, the newest last.
+    fn draw_events(&self, dims: &PanelDims, game: &gust_core::Game) -> Result<(), std::io::Error> {
+        const EVENT_ROWS: usize = 3;
+
+        let latest = game.events.iter().rev().take(EVENT_ROWS).rev();
+        for (row, event) in latest.enumerate() {
+            let day = event.time_in_seconds / game.globe.astronomy.day_length + 1;
+            queue!(
+                stdout(),
+                cursor::MoveTo(dims.x + 2, dims.y + dims.h - 1 - EVENT_ROWS as u16 + row as u16),
+                style::Print(format!("Day {}: {}", day, event.kind))
+            )?;
+        }
+
         Ok(())
     }
+
+    /// Marks the way along a route with dots, over the side of the globe facing the camera.
+    fn draw_route(&self, dims: &PanelDims, game: &gust_core::Game, route: &Route, cell_color: &impl Fn(usize) -> style::Color) -> Result<(), std::io::Error> {
+        // Angle in radians, seen from the center of the globe, between the dots marking the route
+        const DOT_SPACING: f64 = 0.01;
+
+        for leg in route.waypoints.windows(2) {
+            let dots = (leg[0].central_angle_to(&leg[1]) / DOT_SPACING).ceil().max(1.0) as usize;
+            for dot in 0..=dots {
+                let position = leg[0].interpolate(&leg[1], dot as f64 / dots as f64);
+                let Some((x, y)) = self.visible_screen_point(&position, dims) else {
+                    continue;
+                };
+
+                let styled = '•'.with(ROUTE_COLOR).on(cell_color(game.globe.cell_at(&position)));
+                queue!(stdout(), cursor::MoveTo(x, y), style::PrintStyledContent(styled))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Marks the towns and cities over the side of the globe facing the camera. Villages are too small to show.
+    fn draw_settlements(&self, dims: &PanelDims, game: &gust_core::Game, cell_color: &impl Fn(usize) -> style::Color) -> Result<(), std::io::Error> {
+        for settlement in game.settlements.iter() {
+            let mark = match settlement.kind {
+                SettlementKind::City => '■',
+                SettlementKind::Town => '▪',
+                SettlementKind::Village => continue,
+            };
+            let Some((x, y)) = self.visible_screen_point(&settlement.position, dims) else {
+                continue;
+            };
+
+            let styled = mark.with(SETTLEMENT_COLOR).on(cell_color(settlement.cell));
+            queue!(stdout(), cursor::MoveTo(x, y), style::PrintStyledContent(styled))?;
+        }
+
+        Ok(())
+    }
+
+    /// Where a position on the globe shows in the panel, or `None` if it lies on the far side of the globe
+    /// or outside the panel.
+    fn visible_screen_point(&self, position: &Position, dims: &PanelDims) -> Option<(u16, u16)> {
+        let point = position.to_unit_vector();
+        if point.dot(self.camera_position) <= 1.0 {
+            return None;
+        }
+
+        let screen = self.project_camera_vertex_to_screen_space(self.transform_world_vertex_to_camera_space(point), dims);
+        if screen.x < (dims.x + 1) as f32 || screen.y < (dims.y + 1) as f32 || screen.x >= (dims.x + dims.w - 1) as f32 || screen.y >= (dims.y + dims.h - 1) as f32 {
+            return None;
+        }
+        Some((screen.x as u16, screen.y as u16))
+    }
+}
+
+/// Brightness of the surface by the cosine of the angle between it and the sun, dimmed on the night side
+/// of the terminator and fading across a band of twilight.
+fn daylight(sun_cosine: f32) -> f32 {
+    const NIGHT_BRIGHTNESS: f32 = 0.35;
+    const TWILIGHT_WIDTH: f32 = 0.1;
+
+    let day_fraction = ((sun_cosine + TWILIGHT_WIDTH) / (2.0 * TWILIGHT_WIDTH)).clamp(0.0, 1.0);
+    NIGHT_BRIGHTNESS + (1.0 - NIGHT_BRIGHTNESS) * day_fraction
+}
+
+/// Color of a cell by its biome, tinted by the speed of its current in m/s and shaded by the daylight.
+fn color(biome: Biome, current_speed: f32, light: f32) -> style::Color {
+    const CURRENT_TINT: (f32, f32, f32) = (120.0, 230.0, 255.0);
+    const FULL_TINT_SPEED: f32 = 0.5;
+
+    let (r, g, b) = biome_color(biome);
+    let tint = (current_speed / FULL_TINT_SPEED).min(1.0);
+    let shade = |channel: u8, tint_channel: f32| ((channel as f32 + (tint_channel - channel as f32) * tint) * light) as u8;
+    style::Color::Rgb {
+        r: shade(r, CURRENT_TINT.0),
+        g: shade(g, CURRENT_TINT.1),
+        b: shade(b, CURRENT_TINT.2),
+    }
+}
+
+fn biome_color(biome: Biome) -> (u8, u8, u8) {
+    match biome {
+        Biome::Ocean => (20, 50, 120),
+        Biome::ShallowSea => (40, 90, 160),
+        Biome::Reef => (50, 160, 170),
+        Biome::PackIce => (190, 215, 230),
+        Biome::Lake => (60, 110, 190),
+        Biome::IceSheet => (240, 245, 250),
+        Biome::Tundra => (150, 160, 140),
+        Biome::Taiga => (50, 95, 70),
+        Biome::TemperateForest => (60, 130, 60),
+        Biome::Grassland => (140, 170, 80),
+        Biome::Desert => (220, 195, 130),
+        Biome::Savanna => (185, 175, 90),
+        Biome::Jungle => (25, 110, 40),
+        Biome::Mountains => (130, 115, 100),
+    }
 }
 
 impl super::panel::Panel<Log> {
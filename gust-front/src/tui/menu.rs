@@ -10,21 +10,62 @@ use crossterm::{
 use super::{
     draw_panel,
     panel::{Panel, PanelDims},
+    prompt::{Prompt, PromptResult},
 };
 
+/// What the player picked in the menu.
+pub enum MenuChoice {
+    /// Index of the chosen option.
+    Option(usize),
+    /// Text submitted to the open prompt, and the index of the toggle option picked with it.
+    Text(String, usize),
+}
+
 pub struct Menu {
     state: u8,
     options: Vec<String>,
+    prompt: Option<Prompt>,
 }
 
 impl Menu {
     pub fn new(options: Vec<String>) -> Self {
-        Self { state: 0, options }
+        Self { state: 0, options, prompt: None }
+    }
+
+    /// Replaces the options with a prompt for the player to type into, with options to pick from alongside the text.
+    pub fn open_prompt(&mut self, title: &str, toggles: Vec<String>) {
+        self.prompt = Some(Prompt::new(title, toggles));
+    }
+
+    pub fn close_prompt(&mut self) {
+        self.prompt = None;
     }
 
-    pub fn handle_key_event(&mut self, event: Event) {
-        match event {
-            Event::Key(key_event) => match key_event.code {
+    /// Shows an error under the open prompt, such as when its text couldn't be understood.
+    pub fn set_prompt_error(&mut self, error: String) {
+        if let Some(prompt) = &mut self.prompt {
+            prompt.error = Some(error);
+        }
+    }
+
+    pub fn is_prompting(&self) -> bool {
+        self.prompt.is_some()
+    }
+
+    pub fn handle_key_event(&mut self, event: Event) -> Option<MenuChoice> {
+        if let Some(prompt) = &mut self.prompt {
+            return match prompt.handle_key_event(event) {
+                PromptResult::Pending => None,
+                PromptResult::Submitted(text, toggle) => Some(MenuChoice::Text(text, toggle)),
+                PromptResult::Cancelled => {
+                    self.prompt = None;
+                    None
+                }
+            };
+        }
+
+        if let Event::Key(key_event) = event {
+            match key_event.code {
                 KeyCode::Up => {
                     if self.state == 0 {
                         self.state = self.options.len() as u8 - 1;
@@ -39,13 +80,19 @@ impl Menu {
                         self.state += 1;
                     }
                 }
+                KeyCode::Enter => return Some(MenuChoice::Option(self.state as usize)),
                 _ => (),
-            },
-            _ => (),
+            }
         }
+
+        None
     }
 
-    pub fn draw(&self, dims: PanelDims, game: &gust_core::Game) -> Result<(), &str> {
+    pub fn draw(&self, dims: PanelDims, _game: &gust_core::Game) -> Result<(), &str> {
+        if let Some(prompt) = &self.prompt {
+            return prompt.draw(&dims).map_err(|_| "Couldn't draw prompt");
+        }
+
         let option_count = self.options.len() as u16;
 
         if dims.h < 2 {
@@ -0,0 +1,99 @@
+use std::io::stdout;
+
+use crossterm::{
+    cursor,
+    event::{Event, KeyCode},
+    queue, style,
+};
+
+use super::panel::PanelDims;
+
+pub enum PromptResult {
+    Pending,
+    /// The typed text, along with the index of the toggle option picked alongside it.
+    Submitted(String, usize),
+    Cancelled,
+}
+
+/// A line of text typed in by the player, shown in place of the menu.
+pub struct Prompt {
+    title: String,
+    input: String,
+    pub error: Option<String>,
+    /// Options the player can cycle through with Tab while typing, if any.
+    toggles: Vec<String>,
+    toggle: usize,
+}
+
+impl Prompt {
+    pub fn new(title: &str, toggles: Vec<String>) -> Self {
+        Self {
+            title: title.to_string(),
+            input: String::new(),
+            error: None,
+            toggles,
+            toggle: 0,
+        }
+    }
+
+    pub fn handle_key_event(&mut self, event: Event) -> PromptResult {
+        if let Event::Key(key_event) = event {
+            match key_event.code {
+                KeyCode::Enter => return PromptResult::Submitted(self.input.clone(), self.toggle),
+                KeyCode::Esc => return PromptResult::Cancelled,
+                KeyCode::Tab if !self.toggles.is_empty() => {
+                    self.toggle = (self.toggle + 1) % self.toggles.len();
+                }
+                KeyCode::Backspace => {
+                    self.input.pop();
+                }
+                KeyCode::Char(c) => {
+                    self.input.push(c);
+                }
+                _ => (),
+            }
+        }
+
+        PromptResult::Pending
+    }
+
+    pub fn draw(&self, dims: &PanelDims) -> Result<(), std::io::Error> {
+        if dims.h < 10 || dims.w < 6 {
+            return Ok(());
+        }
+
+        let text_w = (dims.w - 4) as usize;
+        // Show the end of the input if it doesn't fit, since that's where the player is typing.
+        let visible_input: String = self.input.chars().rev().take(text_w - 1).collect::<Vec<_>>().into_iter().rev().collect();
+
+        let hint = if self.toggles.is_empty() {
+            "Enter to confirm, Esc to cancel"
+        } else {
+            "Enter to confirm, Tab to switch, Esc to cancel"
+        };
+
+        queue!(
+            stdout(),
+            cursor::MoveTo(dims.x + 2, dims.y + 2),
+            style::Print(truncate(&self.title, text_w)),
+            cursor::MoveTo(dims.x + 2, dims.y + 4),
+            style::Print(format!("{}_", visible_input)),
+            cursor::MoveTo(dims.x + 2, dims.y + dims.h - 2),
+            style::Print(truncate(hint, text_w)),
+        )?;
+
+        if let Some(toggle) = self.toggles.get(self.toggle) {
+            queue!(stdout(), cursor::MoveTo(dims.x + 2, dims.y + 6), style::Print(truncate(&format!("< {} >", toggle), text_w)))?;
+        }
+
+        if let Some(error) = &self.error {
+            queue!(stdout(), cursor::MoveTo(dims.x + 2, dims.y + 7), style::Print(truncate(error, text_w)))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn truncate(text: &str, width: usize) -> String {
+    text.chars().take(width).collect()
+}
@@ -2,14 +2,48 @@ use std::io::stdout;
 
 use crossterm::{cursor, queue, style};
 
+use gust_core::world::position::notation::CoordinateFormat;
+
 use super::{
     draw_panel,
     panel::{Panel, PanelDims},
 };
 
-pub struct Status {}
+pub struct Status {
+    /// Place in the plan of the task picked out to cancel, move, or plan new tasks before. `None` for the end of the plan.
+    selected: Option<usize>,
+}
+
+impl Status {
+    pub fn new() -> Self {
+        Self { selected: None }
+    }
+
+    /// The place in the plan of the task picked out, if the plan still reaches that far.
+    pub fn selected(&self, plan_len: usize) -> Option<usize> {
+        self.selected.filter(|&index| index < plan_len)
+    }
 
-impl Status {}
+    pub fn select(&mut self, index: Option<usize>) {
+        self.selected = index;
+    }
+
+    /// Picks out the task after the one picked out, or the first. Past the last, the end of the plan is picked out.
+    pub fn select_next(&mut self, plan_len: usize) {
+        self.selected = match self.selected(plan_len) {
+            Some(index) => Some(index + 1).filter(|&next| next < plan_len),
+            None => Some(0).filter(|_| plan_len > 0),
+        };
+    }
+
+    /// Picks out the task before the one picked out, or the last. Before the first, the end of the plan is picked out.
+    pub fn select_previous(&mut self, plan_len: usize) {
+        self.selected = match self.selected(plan_len) {
+            Some(index) => index.checked_sub(1),
+            None => plan_len.checked_sub(1),
+        };
+    }
+}
 
 impl Panel<Status> {
     pub fn draw(&self, dims: PanelDims, game: &gust_core::Game) -> Result<(), std::io::Error> {
@@ -20,13 +54,92 @@ impl Panel<Status> {
             cursor::MoveTo(dims.x + 2, dims.y + 2),
             style::Print(game.player.name.to_string()),
             cursor::MoveTo(dims.x + 2, dims.y + 4),
-            style::Print(&game.player.position),
+            style::Print(format!(
+                "{}, variation {}",
+                game.player.believed_position.format(CoordinateFormat::DegreesMinutes),
+                variation(game)
+            )),
             cursor::MoveTo(dims.x + 2, dims.y + 6),
             style::Print(format!("{} silver", &game.player.money)),
             cursor::MoveTo(dims.x + 2, dims.y + 8),
             style::Print(&game.player.task),
-        )
+            cursor::MoveTo(dims.x + 2, dims.y + 10),
+            style::Print(local_time(game)),
+        )?;
+
+        // Where the player believes they are, by the nearest settlement
+        let believed = &game.player.believed_position;
+        if let Some(settlement) = game.nearest_settlement(believed) {
+            let place = match game.settlement_at(believed) {
+                Some(here) if here.name == settlement.name => "In",
+                _ => "Near",
+            };
+            queue!(
+                stdout(),
+                cursor::MoveTo(dims.x + 2, dims.y + 12),
+                style::Print(format!("{} {} ({}, {} people)", place, settlement.name, settlement.kind, settlement.population)),
+            )?;
+        }
+
+        if let Some(ship) = &game.player.ship {
+            queue!(
+                stdout(),
+                cursor::MoveTo(dims.x + 2, dims.y + 14),
+                style::Print(format!(
+                    "{} {}, {} hands, {:.0}/{:.0} t, {:.0}%",
+                    ship.hull,
+                    ship.name,
+                    ship.crew,
+                    ship.cargo_weight(),
+                    ship.cargo_capacity,
+                    ship.condition * 100.0
+                )),
+            )?;
+        }
+
+        // The plan, as far as there is room for it, with the task picked out underlined
+        let first_row = dims.y + 16;
+        let selected = self.kind.selected(game.player.plan.len());
+        for (index, task) in game.player.plan.iter().enumerate() {
+            let y = first_row + index as u16;
+            if y + 1 >= dims.y + dims.h {
+                break;
+            }
+            let line = format!("{}. {}", index + 1, task);
+            if selected == Some(index) {
+                queue!(
+                    stdout(),
+                    cursor::MoveTo(dims.x + 2, y),
+                    style::SetAttribute(style::Attribute::Underlined),
+                    style::Print(line),
+                    style::SetAttribute(style::Attribute::NoUnderline)
+                )?;
+            } else {
+                queue!(stdout(), cursor::MoveTo(dims.x + 2, y), style::Print(line))?;
+            }
+        }
+
+        Ok(())
     }
 }
 
+/// The magnetic declination where the player believes they are, as navigators write it, such as 4°E.
+fn variation(game: &gust_core::Game) -> String {
+    let declination = game.declination_at(&game.player.believed_position).to_degrees();
+    let side = if declination < 0.0 { 'W' } else { 'E' };
+    format!("{:.0}°{}", declination.abs(), side)
+}
+
+/// The day of the game, the local solar time and the season where the player is.
+fn local_time(game: &gust_core::Game) -> String {
+    let astronomy = &game.globe.astronomy;
+    let position = &game.player.position;
+
+    let day = game.time_in_seconds / astronomy.day_length + 1;
+    let minutes = astronomy.local_solar_time(game.time_in_seconds, position) * 24 * 60 / astronomy.day_length;
+    let season = astronomy.season(game.time_in_seconds, position);
+
+    format!("Day {}, {:02}:{:02}, {}", day, minutes / 60, minutes % 60, season)
+}
+
 pub type StatusPanel = Panel<Status>;